@@ -503,9 +503,51 @@ quickcheck! {
         assert!(petgraph::algo::is_isomorphic(&g1, &g2));
     }
 
+    fn bytes_graphmap_to_graphmap(g1: UnGraphMap<i8, i8>) -> () {
+        let g2 = UnGraphMap::<i8, i8>::from_bytes(&g1.to_bytes().unwrap()).unwrap();
+        assert_equal(g1.nodes(), g2.nodes());
+        assert_equal(g1.all_edges(), g2.all_edges());
+    }
+
     // graph to graphmap is not always possible because of parallel edges
 }
 
+#[test]
+fn bytes_graphmap_size() {
+    let gr: DiGraphMap<u32, u32> = (0..1000).map(|i| (i, (i * 7) % 1000, i % 100)).collect();
+    let bytes = gr.to_bytes().unwrap();
+    let serde_bytes = encode!(&gr);
+    assert!(bytes.len() < serde_bytes.len() / 2);
+
+    let gr2 = DiGraphMap::<u32, u32>::from_bytes(&bytes).unwrap();
+    assert_equal(gr.nodes(), gr2.nodes());
+    assert_equal(gr.all_edges(), gr2.all_edges());
+}
+
+#[test]
+fn bytes_graphmap_invalid() {
+    let gr = DiGraphMap::<u8, ()>::from_edges(&[(1, 2)]);
+    let mut bytes = gr.to_bytes().unwrap();
+    // the endpoint index of the last edge
+    *bytes.last_mut().unwrap() = 5;
+    assert!(DiGraphMap::<u8, ()>::from_bytes(&bytes).is_err());
+    assert!(DiGraphMap::<u8, ()>::from_bytes(&bytes[..2]).is_err());
+}
+
+#[test]
+fn bytes_graphmap_weight_error() {
+    struct Unserializable;
+    impl serde::Serialize for Unserializable {
+        fn serialize<S: serde::Serializer>(&self, _: S) -> Result<S::Ok, S::Error> {
+            Err(serde::ser::Error::custom("unserializable weight"))
+        }
+    }
+
+    let mut gr = DiGraphMap::new();
+    gr.add_edge(1, 2, Unserializable);
+    assert!(gr.to_bytes().is_err());
+}
+
 #[test]
 fn json_graphmap_integer() {
     let mut gr: GraphMap<i32, u32, Directed> = GraphMap::from_edges(&[
@@ -564,3 +606,118 @@ fn json_graphmap_struct() {
         (1, 2.)
     );
 }
+
+#[test]
+fn graphmap_serializes_like_graph() {
+    let mut gr: UnGraphMap<i32, u32> = UnGraphMap::from_edges(&[(3, 1, 0), (1, 2, 1), (2, 2, 2)]);
+    gr.add_node(9);
+    let as_graph: UnGraph<i32, u32> = gr.clone().into_graph();
+    assert_eq!(tojson!(&gr), tojson!(&as_graph));
+    assert_eq!(encode!(&gr), encode!(&as_graph));
+
+    let gr_deser: UnGraphMap<i32, u32> = rejson!(&gr);
+    assert_equal(gr.nodes(), gr_deser.nodes());
+    assert_equal(gr.all_edges(), gr_deser.all_edges());
+}
+
+#[test]
+fn json_graphmap_non_clone_weight() {
+    use serde_derive::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Token(String);
+
+    let mut gr = DiGraphMap::new();
+    gr.add_edge(1, 2, Token("a".to_string()));
+    gr.add_edge(2, 3, Token("b".to_string()));
+    let g2: DiGraph<i32, Token> = fromjson!(tojson!(&gr));
+    assert_eq!(g2.node_count(), 3);
+    assert_eq!(g2.edge_weight(edge_index(1)), Some(&Token("b".to_string())));
+}
+
+#[test]
+fn json_graphmap_move_only_weight() {
+    use serde_derive::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Token(String);
+
+    let mut gr = DiGraphMap::new();
+    gr.add_edge(1, 2, Token("a".to_string()));
+    gr.add_edge(2, 3, Token("b".to_string()));
+    gr.add_node(4);
+    let gr_deser: DiGraphMap<i32, Token> = rejson!(&gr);
+    assert_equal(gr.nodes(), gr_deser.nodes());
+    assert_equal(gr.all_edges(), gr_deser.all_edges());
+}
+
+#[test]
+fn graph_to_graphmap() {
+    let mut g = Graph::<_, _, Directed>::new();
+    let a = g.add_node('a');
+    let b = g.add_node('b');
+    let c = g.add_node('c');
+    g.add_edge(a, b, 1);
+    g.add_edge(c, a, 2);
+    g.add_edge(c, c, 3);
+
+    let from_json: DiGraphMap<char, i32> = rejson!(&g);
+    let from_bincode: DiGraphMap<char, i32> = recode!(&g);
+    for gm in &[from_json, from_bincode] {
+        assert_equal(gm.nodes(), vec!['a', 'b', 'c']);
+        assert_equal(
+            gm.all_edges(),
+            vec![('a', 'b', &1), ('c', 'a', &2), ('c', 'c', &3)],
+        );
+    }
+}
+
+#[test]
+fn graphmap_invalid() {
+    let data = r#"{"nodes":[1,2],"node_holes":[],"edge_property":"directed","edges":[[0,2,0]]}"#;
+    assert!(serde_json::from_str::<DiGraphMap<i32, i32>>(data).is_err());
+    let data = r#"{"nodes":[1,2],"node_holes":[],"edge_property":"directed","edges":[null]}"#;
+    assert!(serde_json::from_str::<DiGraphMap<i32, i32>>(data).is_err());
+    let data = r#"{"nodes":[1,2],"node_holes":[],"edge_property":"undirected","edges":[]}"#;
+    assert!(serde_json::from_str::<DiGraphMap<i32, i32>>(data).is_err());
+    let data = r#"{"nodes":[1,2],"node_holes":[0],"edge_property":"directed","edges":[]}"#;
+    assert!(serde_json::from_str::<DiGraphMap<i32, i32>>(data).is_err());
+    let data = r#"{"nodes":[1,2],"edge_property":"directed","edges":[[0,1,7]]}"#;
+    let gm = serde_json::from_str::<DiGraphMap<i32, i32>>(data).unwrap();
+    assert_eq!(gm.edge_weight(1, 2), Some(&7));
+}
+
+#[test]
+fn graphmap_as_edge_list() {
+    use petgraph::graphmap::AsEdgeList;
+
+    let mut gr: UnGraphMap<i32, u32> = UnGraphMap::from_edges(&[(3, 1, 0), (1, 2, 1), (2, 2, 2)]);
+    gr.add_node(9);
+    assert_eq!(
+        tojson!(&AsEdgeList(&gr)),
+        r#"{"nodes":[9],"edges":[[1,3,0],[1,2,1],[2,2,2]]}"#
+    );
+
+    let AsEdgeList(from_json): AsEdgeList<UnGraphMap<i32, u32>> = rejson!(&AsEdgeList(&gr));
+    let AsEdgeList(from_bincode): AsEdgeList<UnGraphMap<i32, u32>> = recode!(&AsEdgeList(&gr));
+    for gm in &[from_json, from_bincode] {
+        assert_equal(gm.nodes(), vec![1, 3, 2, 9]);
+        assert_equal(gm.all_edges(), gr.all_edges());
+    }
+}
+
+#[test]
+fn graphmap_as_edge_list_size() {
+    use petgraph::graphmap::AsEdgeList;
+
+    let mut gr: DiGraphMap<u32, u32> = (0..100).map(|i| (i, (i * 7) % 100, i)).collect();
+    for i in 100..110 {
+        gr.add_node(i);
+    }
+    assert!(tojson!(&AsEdgeList(&gr)).len() < tojson!(&gr).len());
+    assert!(encode!(&AsEdgeList(&gr)).len() < encode!(&gr).len());
+
+    let AsEdgeList(gr2): AsEdgeList<DiGraphMap<u32, u32>> = recode!(AsEdgeList(gr.clone()));
+    assert_eq!(gr2.node_count(), gr.node_count());
+    assert_equal(gr.all_edges(), gr2.all_edges());
+}
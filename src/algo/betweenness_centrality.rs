@@ -0,0 +1,111 @@
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+use crate::visit::{GraphProp, IntoNeighbors, IntoNodeIdentifiers, NodeIndexable};
+
+/// \[Generic\] Compute the *edge betweenness centrality* of every edge.
+///
+/// The betweenness of an edge is the sum, over all pairs of nodes `s`, `t`, of
+/// the fraction of shortest `s`-`t` paths that pass through the edge. Paths are
+/// counted by number of edges, i.e. the graph is treated as unweighted.
+///
+/// Uses [Brandes' algorithm][1], with its dependency accumulation carried over
+/// to edges, in **O(|V| * |E|)** time. The scores are not normalized. For an
+/// undirected graph each unordered pair of nodes is counted once.
+///
+/// Returns a `HashMap` from edge endpoints to score. For an undirected graph the
+/// endpoints of each key are ordered `(a, b)` with `a <= b`, matching the
+/// canonical edge ids of `GraphMap`. Self loops are never on a shortest path
+/// and get no entry.
+///
+/// [1]: https://doi.org/10.1080/0022250X.2001.9990249
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::edge_betweenness_centrality;
+/// use petgraph::graph::{node_index, UnGraph};
+///
+/// // 0 - 1 - 2
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+/// let scores = edge_betweenness_centrality(&graph);
+/// assert_eq!(scores[&(node_index(0), node_index(1))], 2.);
+/// assert_eq!(scores[&(node_index(1), node_index(2))], 2.);
+/// ```
+pub fn edge_betweenness_centrality<G>(graph: G) -> HashMap<(G::NodeId, G::NodeId), f64>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    G::NodeId: Ord + Hash,
+{
+    let directed = graph.is_directed();
+    let key = |a: G::NodeId, b: G::NodeId| {
+        if directed || a <= b {
+            (a, b)
+        } else {
+            (b, a)
+        }
+    };
+
+    let mut scores = HashMap::new();
+    for a in graph.node_identifiers() {
+        for b in graph.neighbors(a) {
+            if a != b {
+                scores.insert(key(a, b), 0.);
+            }
+        }
+    }
+
+    let bound = graph.node_bound();
+    let mut order = Vec::with_capacity(bound);
+    let mut predecessors: Vec<Vec<G::NodeId>> = vec![Vec::new(); bound];
+    let mut sigma = vec![0.; bound];
+    let mut dist = vec![std::usize::MAX; bound];
+    let mut delta = vec![0.; bound];
+    let mut queue = VecDeque::new();
+
+    for s in graph.node_identifiers() {
+        order.clear();
+        for i in 0..bound {
+            predecessors[i].clear();
+            sigma[i] = 0.;
+            dist[i] = std::usize::MAX;
+            delta[i] = 0.;
+        }
+        let si = graph.to_index(s);
+        sigma[si] = 1.;
+        dist[si] = 0;
+        queue.push_back(s);
+
+        while let Some(v) = queue.pop_front() {
+            order.push(v);
+            let vi = graph.to_index(v);
+            for w in graph.neighbors(v) {
+                let wi = graph.to_index(w);
+                if dist[wi] == std::usize::MAX {
+                    dist[wi] = dist[vi] + 1;
+                    queue.push_back(w);
+                }
+                if dist[wi] == dist[vi] + 1 {
+                    sigma[wi] += sigma[vi];
+                    predecessors[wi].push(v);
+                }
+            }
+        }
+
+        while let Some(w) = order.pop() {
+            let wi = graph.to_index(w);
+            for &v in &predecessors[wi] {
+                let vi = graph.to_index(v);
+                let c = sigma[vi] / sigma[wi] * (1. + delta[wi]);
+                *scores.get_mut(&key(v, w)).unwrap() += c;
+                delta[vi] += c;
+            }
+        }
+    }
+
+    if !directed {
+        for score in scores.values_mut() {
+            *score /= 2.;
+        }
+    }
+    scores
+}
@@ -5,7 +5,8 @@ use std::hash::Hash;
 
 use crate::algo::Measure;
 use crate::scored::MinScored;
-use crate::visit::{EdgeRef, IntoEdges, VisitMap, Visitable};
+use crate::visit::{EdgeRef, IntoEdges, IntoEdgesDirected, VisitMap, Visitable};
+use crate::Direction::{Incoming, Outgoing};
 
 /// \[Generic\] Dijkstra's shortest path algorithm.
 ///
@@ -120,3 +121,133 @@ where
     }
     scores
 }
+
+/// \[Generic\] Bidirectional Dijkstra's shortest path algorithm.
+///
+/// Compute the shortest path from `start` to `goal` by running Dijkstra's
+/// algorithm forward from `start` and backward from `goal` at the same time,
+/// following incoming edges in the backward search. The search stops as soon
+/// as no path through the unexplored part of the graph can be shorter than
+/// the best path found where the two searches meet, which usually explores
+/// far fewer nodes than a single search.
+///
+/// The graph should be `Visitable` and implement `IntoEdgesDirected`. The
+/// function `edge_cost` should return the cost for a particular edge, which is
+/// used to compute path costs. Edge costs must be non-negative.
+///
+/// Returns the total cost and the path of subsequent `NodeId` from `start` to
+/// `goal`, or `None` if `goal` is not reachable.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::bidirectional_dijkstra;
+/// use petgraph::Graph;
+///
+/// let mut graph = Graph::<(), u32>::new();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// let d = graph.add_node(());
+/// graph.extend_with_edges(&[(a, b, 1), (b, d, 5), (a, c, 2), (c, d, 3)]);
+///
+/// assert_eq!(
+///     bidirectional_dijkstra(&graph, a, d, |e| *e.weight()),
+///     Some((5, vec![a, c, d]))
+/// );
+/// assert_eq!(bidirectional_dijkstra(&graph, d, a, |e| *e.weight()), None);
+/// ```
+pub fn bidirectional_dijkstra<G, F, K>(
+    graph: G,
+    start: G::NodeId,
+    goal: G::NodeId,
+    mut edge_cost: F,
+) -> Option<(K, Vec<G::NodeId>)>
+where
+    G: IntoEdgesDirected + Visitable,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let zero_score = K::default();
+    // Index 0 is the forward search from `start`, 1 the backward search from
+    // `goal`.
+    let directions = [Outgoing, Incoming];
+    let mut visited = [graph.visit_map(), graph.visit_map()];
+    let mut scores = [HashMap::new(), HashMap::new()];
+    let mut predecessor = [HashMap::new(), HashMap::new()];
+    let mut visit_next = [BinaryHeap::new(), BinaryHeap::new()];
+    scores[0].insert(start, zero_score);
+    scores[1].insert(goal, zero_score);
+    visit_next[0].push(MinScored(zero_score, start));
+    visit_next[1].push(MinScored(zero_score, goal));
+
+    // The best path found so far, with the node where the searches meet.
+    let mut best = if start == goal {
+        Some((zero_score, start))
+    } else {
+        None
+    };
+
+    while let (Some(f), Some(b)) = (visit_next[0].peek(), visit_next[1].peek()) {
+        let (forward, backward) = (f.0, b.0);
+        if let Some((best_score, _)) = best {
+            if forward + backward >= best_score {
+                break;
+            }
+        }
+        let side = if forward <= backward { 0 } else { 1 };
+        let MinScored(node_score, node) = visit_next[side].pop().unwrap();
+        if visited[side].is_visited(&node) {
+            continue;
+        }
+        for edge in graph.edges_directed(node, directions[side]) {
+            let next = if edge.source() == node {
+                edge.target()
+            } else {
+                edge.source()
+            };
+            if visited[side].is_visited(&next) {
+                continue;
+            }
+            let next_score = node_score + edge_cost(edge);
+            match scores[side].entry(next) {
+                Occupied(ent) => {
+                    if next_score < *ent.get() {
+                        *ent.into_mut() = next_score;
+                    } else {
+                        continue;
+                    }
+                }
+                Vacant(ent) => {
+                    ent.insert(next_score);
+                }
+            }
+            visit_next[side].push(MinScored(next_score, next));
+            predecessor[side].insert(next, node);
+            if let Some(&other_score) = scores[1 - side].get(&next) {
+                let score = next_score + other_score;
+                match best {
+                    Some((best_score, _)) if best_score <= score => {}
+                    _ => best = Some((score, next)),
+                }
+            }
+        }
+        visited[side].visit(node);
+    }
+
+    best.map(|(score, meeting)| {
+        let mut path = vec![meeting];
+        let mut node = meeting;
+        while let Some(&prev) = predecessor[0].get(&node) {
+            path.push(prev);
+            node = prev;
+        }
+        path.reverse();
+        let mut node = meeting;
+        while let Some(&next) = predecessor[1].get(&node) {
+            path.push(next);
+            node = next;
+        }
+        (score, path)
+    })
+}
@@ -0,0 +1,94 @@
+use std::collections::VecDeque;
+
+use crate::visit::{GraphProp, IntoNeighbors, IntoNodeIdentifiers, NodeIndexable};
+
+/// \[Generic\] Compute the *girth* of a graph, i.e. the length of its shortest cycle.
+///
+/// The length of a cycle is its number of edges. A self loop is a cycle of
+/// length 1. Returns `None` if the graph is acyclic.
+///
+/// For an undirected graph a breadth-first search is started from each node;
+/// the first non-tree edge met closes the shortest cycle through that search
+/// tree. The undirected case assumes there are no parallel edges (as in
+/// `GraphMap`): a pair of parallel edges is not reported as a cycle of length 2.
+///
+/// For a directed graph only directed cycles are considered: the result is the
+/// length of the shortest path from a node back to itself.
+///
+/// Computes in **O(|V| * (|V| + |E|))** time.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::girth;
+/// use petgraph::graph::UnGraph;
+///
+/// // A triangle 0-1-2 sharing node 2 with the square 2-3-4-5.
+/// let graph = UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 0),
+///     (2, 3), (3, 4), (4, 5), (5, 2),
+/// ]);
+/// assert_eq!(girth(&graph), Some(3));
+///
+/// let tree = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (1, 3)]);
+/// assert_eq!(girth(&tree), None);
+/// ```
+pub fn girth<G>(graph: G) -> Option<usize>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    let directed = graph.is_directed();
+    let mut best: Option<usize> = None;
+
+    let mut dist = vec![std::usize::MAX; graph.node_bound()];
+    let mut parent = vec![std::usize::MAX; graph.node_bound()];
+    let mut queue = VecDeque::new();
+
+    for start in graph.node_identifiers() {
+        for d in dist.iter_mut() {
+            *d = std::usize::MAX;
+        }
+        let s = graph.to_index(start);
+        dist[s] = 0;
+        parent[s] = std::usize::MAX;
+        queue.clear();
+        queue.push_back(start);
+
+        'bfs: while let Some(node) = queue.pop_front() {
+            let u = graph.to_index(node);
+            // No shorter cycle can be closed from this depth onwards.
+            if let Some(b) = best {
+                if (directed && dist[u] + 1 >= b) || (!directed && 2 * dist[u] + 1 >= b) {
+                    break;
+                }
+            }
+            for succ in graph.neighbors(node) {
+                let v = graph.to_index(succ);
+                if v == u {
+                    best = Some(1);
+                    break 'bfs;
+                }
+                if directed {
+                    if v == s {
+                        best = Some(best.map_or(dist[u] + 1, |b| b.min(dist[u] + 1)));
+                        break 'bfs;
+                    }
+                    if dist[v] == std::usize::MAX {
+                        dist[v] = dist[u] + 1;
+                        queue.push_back(succ);
+                    }
+                } else if dist[v] == std::usize::MAX {
+                    dist[v] = dist[u] + 1;
+                    parent[v] = u;
+                    queue.push_back(succ);
+                } else if parent[u] != v {
+                    let len = dist[u] + dist[v] + 1;
+                    best = Some(best.map_or(len, |b| b.min(len)));
+                }
+            }
+        }
+        if best == Some(1) {
+            break;
+        }
+    }
+    best
+}
@@ -0,0 +1,71 @@
+use crate::visit::{IntoNeighbors, IntoNodeIdentifiers, NodeIndexable};
+
+/// \[Generic\] Compute a maximal independent set using the minimum-degree greedy
+/// heuristic.
+///
+/// An independent set is a set of nodes no two of which are adjacent. The
+/// algorithm repeatedly picks a node of minimum degree among the remaining
+/// nodes, adds it to the set and removes it together with its neighbors.
+///
+/// The result is *maximal* (no node can be added to it) but it is only an
+/// approximation of a *maximum* independent set, which is NP-hard to find.
+///
+/// The graph is treated as undirected and self loops are ignored.
+///
+/// Computes in **O(|V|² + |E|)** time.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::greedy_independent_set;
+/// use petgraph::graph::UnGraph;
+///
+/// // a star: the leaves are picked before the center
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3)]);
+/// let mut set: Vec<_> = greedy_independent_set(&graph).iter().map(|n| n.index()).collect();
+/// set.sort();
+/// assert_eq!(set, vec![1, 2, 3]);
+/// ```
+pub fn greedy_independent_set<G>(graph: G) -> Vec<G::NodeId>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let bound = graph.node_bound();
+    let mut adjacency = vec![Vec::new(); bound];
+    for a in graph.node_identifiers() {
+        for b in graph.neighbors(a) {
+            let (ai, bi) = (graph.to_index(a), graph.to_index(b));
+            if ai != bi && !adjacency[ai].contains(&bi) {
+                adjacency[ai].push(bi);
+                adjacency[bi].push(ai);
+            }
+        }
+    }
+
+    let mut remaining = vec![false; bound];
+    for n in graph.node_identifiers() {
+        remaining[graph.to_index(n)] = true;
+    }
+    let mut degree: Vec<usize> = adjacency.iter().map(Vec::len).collect();
+
+    let mut set = Vec::new();
+    loop {
+        let chosen = (0..bound)
+            .filter(|&i| remaining[i])
+            .min_by_key(|&i| degree[i]);
+        let i = match chosen {
+            Some(i) => i,
+            None => break,
+        };
+        set.push(graph.from_index(i));
+        remaining[i] = false;
+        for &j in &adjacency[i] {
+            if remaining[j] {
+                remaining[j] = false;
+                for &k in &adjacency[j] {
+                    degree[k] -= 1;
+                }
+            }
+        }
+    }
+    set
+}
@@ -0,0 +1,134 @@
+use std::collections::{BinaryHeap, HashMap};
+
+use std::hash::Hash;
+
+use crate::algo::{BoundedMeasure, NegativeCycle};
+use crate::scored::MinScored;
+use crate::visit::{
+    EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeIdentifiers, NodeCompactIndexable,
+};
+
+/// \[Generic\] [Johnson's algorithm](https://en.wikipedia.org/wiki/Johnson%27s_algorithm) for all pairs shortest paths.
+///
+/// Compute shortest paths in a weighted graph with positive or negative edge weights (but with no negative cycles).
+/// A potential computed with Bellman–Ford from a virtual source connected to every node is used to
+/// reweight the edges to non-negative costs, after which Dijkstra's algorithm is run from every node.
+///
+/// On sparse graphs this is faster than [`floyd_warshall`](fn.floyd_warshall.html), and it returns
+/// the same result: unreachable pairs have the distance `K::max()`.
+///
+/// Computes in **O(|V|·|E|·log(|V|))** time.
+///
+/// # Arguments
+/// * `graph`: graph with no negative cycle
+/// * `edge_cost`: closure that returns cost of a particular edge
+///
+/// # Returns
+/// * `Ok`: (if graph contains no negative cycle) a hashmap containing all pairs shortest paths
+/// * `Err`: if graph contains negative cycle.
+///
+/// # Examples
+/// ```rust
+/// use petgraph::{prelude::*, Graph};
+/// use petgraph::algo::johnson;
+///
+/// let mut graph: Graph<(), i32> = Graph::new();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// graph.extend_with_edges(&[(a, b, 4), (a, c, 1), (c, b, -2)]);
+///
+/// let res = johnson(&graph, |edge| *edge.weight()).unwrap();
+/// assert_eq!(res[&(a, b)], -1);
+/// assert_eq!(res[&(c, b)], -2);
+/// assert_eq!(res[&(b, a)], std::i32::MAX);
+///
+/// graph.add_edge(b, c, 1);
+/// assert!(johnson(&graph, |edge| *edge.weight()).is_err());
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn johnson<G, F, K>(
+    graph: G,
+    mut edge_cost: F,
+) -> Result<HashMap<(G::NodeId, G::NodeId), K>, NegativeCycle>
+where
+    G: NodeCompactIndexable + IntoEdgeReferences + IntoNodeIdentifiers + GraphProp,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> K,
+    K: BoundedMeasure + Copy,
+{
+    let num_of_nodes = graph.node_count();
+
+    // (source, target, cost) by node index
+    let mut edges = Vec::new();
+    for edge in graph.edge_references() {
+        let (a, b) = (graph.to_index(edge.source()), graph.to_index(edge.target()));
+        let cost = edge_cost(edge);
+        edges.push((a, b, cost));
+        if !graph.is_directed() {
+            edges.push((b, a, cost));
+        }
+    }
+
+    // Bellman-Ford from a virtual source with a zero cost edge to every node.
+    let mut potential = vec![K::default(); num_of_nodes];
+    let mut relaxed = false;
+    for _ in 0..num_of_nodes {
+        relaxed = false;
+        for &(a, b, cost) in &edges {
+            if potential[a] + cost < potential[b] {
+                potential[b] = potential[a] + cost;
+                relaxed = true;
+            }
+        }
+        if !relaxed {
+            break;
+        }
+    }
+    if relaxed {
+        return Err(NegativeCycle(()));
+    }
+
+    // Reweighted edges have non-negative costs.
+    let mut adjacency = vec![Vec::new(); num_of_nodes];
+    for &(a, b, cost) in &edges {
+        adjacency[a].push((b, cost + potential[a] - potential[b]));
+    }
+
+    let mut distance_map: HashMap<(G::NodeId, G::NodeId), K> =
+        HashMap::with_capacity(num_of_nodes * num_of_nodes);
+    let mut visit_next = BinaryHeap::new();
+    for source in 0..num_of_nodes {
+        let mut dist = vec![K::max(); num_of_nodes];
+        let mut visited = vec![false; num_of_nodes];
+        dist[source] = K::default();
+        visit_next.push(MinScored(K::default(), source));
+        while let Some(MinScored(node_score, node)) = visit_next.pop() {
+            if visited[node] {
+                continue;
+            }
+            visited[node] = true;
+            for &(next, cost) in &adjacency[node] {
+                let next_score = node_score + cost;
+                if !visited[next] && next_score < dist[next] {
+                    dist[next] = next_score;
+                    visit_next.push(MinScored(next_score, next));
+                }
+            }
+        }
+
+        for target in 0..num_of_nodes {
+            let distance = if visited[target] {
+                dist[target] - potential[source] + potential[target]
+            } else {
+                K::max()
+            };
+            distance_map.insert(
+                (graph.from_index(source), graph.from_index(target)),
+                distance,
+            );
+        }
+    }
+
+    Ok(distance_map)
+}
@@ -1,10 +1,10 @@
-use std::collections::{BinaryHeap, HashMap};
+use std::collections::{BinaryHeap, HashMap, HashSet};
 
 use std::hash::Hash;
 
-use crate::algo::Measure;
+use crate::algo::{astar, Measure};
 use crate::scored::MinScored;
-use crate::visit::{EdgeRef, IntoEdges, NodeCount, NodeIndexable, Visitable};
+use crate::visit::{EdgeFiltered, EdgeRef, IntoEdges, NodeCount, NodeIndexable, Visitable};
 
 /// \[Generic\] k'th shortest path algorithm.
 ///
@@ -113,3 +113,137 @@ where
     }
     scores
 }
+
+/// \[Generic\] Yen's algorithm for the k shortest loopless paths.
+///
+/// Compute up to `k` shortest paths from `start` to `goal` that do not visit
+/// any node more than once, in order of increasing cost. Unlike
+/// [`k_shortest_path`], which only computes costs, this returns the paths
+/// themselves.
+///
+/// The graph should be `Visitable` and implement `IntoEdges`. The function
+/// `edge_cost` should return the cost for a particular edge, which is used
+/// to compute path costs. Edge costs must be non-negative.
+///
+/// Paths are compared by their node sequence: of several parallel edges
+/// between two consecutive nodes, only the cheapest one is considered.
+///
+/// Computes in **O(k * |V| * (|E| + |V|*log(|V|)))** time (average).
+///
+/// Returns a vector of `(cost, path)` pairs, where each path starts with
+/// `start` and ends with `goal`. It holds fewer than `k` elements if there
+/// are fewer loopless paths.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::k_shortest_simple_paths;
+/// use petgraph::graph::DiGraph;
+/// use petgraph::visit::EdgeRef;
+///
+/// //      1       1
+/// //   a ---> b ---> d
+/// //   |      | 1    ^
+/// //   | 3    v      | 3
+/// //   +----> c -----+
+/// let mut graph = DiGraph::<&str, u32>::new();
+/// let a = graph.add_node("a");
+/// let b = graph.add_node("b");
+/// let c = graph.add_node("c");
+/// let d = graph.add_node("d");
+/// graph.extend_with_edges(&[(a, b, 1), (b, d, 1), (a, c, 3), (b, c, 1), (c, d, 3)]);
+/// let paths = k_shortest_simple_paths(&graph, a, d, 5, |e| *e.weight());
+/// assert_eq!(
+///     paths,
+///     vec![
+///         (2, vec![a, b, d]),
+///         (5, vec![a, b, c, d]),
+///         (6, vec![a, c, d]),
+///     ]
+/// );
+/// ```
+pub fn k_shortest_simple_paths<G, F, K>(
+    graph: G,
+    start: G::NodeId,
+    goal: G::NodeId,
+    k: usize,
+    mut edge_cost: F,
+) -> Vec<(K, Vec<G::NodeId>)>
+where
+    G: IntoEdges + Visitable,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let mut paths: Vec<(K, Vec<G::NodeId>)> = Vec::new();
+    if k == 0 {
+        return paths;
+    }
+    match astar(
+        graph,
+        start,
+        |n| n == goal,
+        &mut edge_cost,
+        |_| K::default(),
+    ) {
+        Some(first) => paths.push(first),
+        None => return paths,
+    }
+
+    let mut seen: HashSet<Vec<G::NodeId>> = HashSet::new();
+    seen.insert(paths[0].1.clone());
+    let mut candidates = BinaryHeap::new();
+
+    while paths.len() < k {
+        let previous = paths[paths.len() - 1].1.clone();
+        let mut root_cost = K::default();
+        for j in 0..previous.len() - 1 {
+            let spur = previous[j];
+            let root = &previous[..=j];
+
+            // Forbid the edges leaving the spur node along any known path with
+            // the same root, and the nodes of the root itself.
+            let removed_edges: HashSet<(G::NodeId, G::NodeId)> = paths
+                .iter()
+                .filter(|(_, path)| path.len() > j + 1 && &path[..=j] == root)
+                .map(|(_, path)| (path[j], path[j + 1]))
+                .collect();
+            let removed_nodes: HashSet<G::NodeId> = root[..j].iter().cloned().collect();
+            let filtered = EdgeFiltered::from_fn(graph, |e: G::EdgeRef| {
+                !removed_nodes.contains(&e.target())
+                    && !removed_edges.contains(&(e.source(), e.target()))
+            });
+
+            if let Some((spur_cost, spur_path)) = astar(
+                &filtered,
+                spur,
+                |n| n == goal,
+                &mut edge_cost,
+                |_| K::default(),
+            ) {
+                let mut path = root[..j].to_vec();
+                path.extend(spur_path);
+                if seen.insert(path.clone()) {
+                    candidates.push(MinScored(root_cost + spur_cost, path));
+                }
+            }
+
+            let next = previous[j + 1];
+            root_cost = root_cost
+                + graph
+                    .edges(spur)
+                    .filter(|e| e.target() == next)
+                    .map(&mut edge_cost)
+                    .fold(None, |min: Option<K>, c| match min {
+                        Some(m) if m <= c => Some(m),
+                        _ => Some(c),
+                    })
+                    .unwrap();
+        }
+
+        match candidates.pop() {
+            Some(MinScored(cost, path)) => paths.push((cost, path)),
+            None => break,
+        }
+    }
+    paths
+}
@@ -0,0 +1,104 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+use crate::visit::{IntoNeighbors, IntoNodeIdentifiers};
+
+/// \[Generic\] Find all *maximal cliques* of an undirected graph.
+///
+/// A clique is a set of nodes that are all pairwise adjacent; it is maximal
+/// if no other node can be added to it. Uses the [Bron–Kerbosch algorithm][1]
+/// with pivoting. Self loops are ignored.
+///
+/// The number of maximal cliques, and therefore the running time, is
+/// exponential in the worst case: **O(3^(|V|/3))**.
+///
+/// Returns a vector of cliques, each a vector of node ids; the order of the
+/// cliques and of the nodes within a clique is arbitrary. Isolated nodes form
+/// cliques of size one.
+///
+/// [1]: https://en.wikipedia.org/wiki/Bron%E2%80%93Kerbosch_algorithm
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::maximal_cliques;
+/// use petgraph::graph::UnGraph;
+///
+/// // a triangle with a pendant edge
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (2, 3)]);
+/// let mut cliques: Vec<Vec<_>> = maximal_cliques(&graph)
+///     .into_iter()
+///     .map(|c| { let mut c: Vec<_> = c.iter().map(|n| n.index()).collect(); c.sort(); c })
+///     .collect();
+/// cliques.sort();
+/// assert_eq!(cliques, vec![vec![0, 1, 2], vec![2, 3]]);
+/// ```
+pub fn maximal_cliques<G>(graph: G) -> Vec<Vec<G::NodeId>>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers,
+    G::NodeId: Eq + Hash,
+{
+    let adjacency: HashMap<G::NodeId, HashSet<G::NodeId>> = graph
+        .node_identifiers()
+        .map(|n| (n, graph.neighbors(n).filter(|&m| m != n).collect()))
+        .collect();
+
+    let mut cliques = Vec::new();
+    if adjacency.is_empty() {
+        return cliques;
+    }
+    let candidates = graph.node_identifiers().collect();
+    bron_kerbosch(
+        &adjacency,
+        &mut Vec::new(),
+        candidates,
+        HashSet::new(),
+        &mut cliques,
+    );
+    cliques
+}
+
+fn bron_kerbosch<N>(
+    adjacency: &HashMap<N, HashSet<N>>,
+    clique: &mut Vec<N>,
+    mut candidates: HashSet<N>,
+    mut excluded: HashSet<N>,
+    cliques: &mut Vec<Vec<N>>,
+) where
+    N: Copy + Eq + Hash,
+{
+    if candidates.is_empty() {
+        if excluded.is_empty() {
+            cliques.push(clique.clone());
+        }
+        return;
+    }
+
+    // Pick the pivot with the most neighbors among the candidates, so that as
+    // few branches as possible are explored.
+    let pivot = candidates
+        .iter()
+        .chain(excluded.iter())
+        .max_by_key(|&v| adjacency[v].intersection(&candidates).count())
+        .copied()
+        .unwrap();
+
+    let branches: Vec<N> = candidates
+        .iter()
+        .filter(|v| !adjacency[&pivot].contains(v))
+        .copied()
+        .collect();
+    for v in branches {
+        let neighbors = &adjacency[&v];
+        clique.push(v);
+        bron_kerbosch(
+            adjacency,
+            clique,
+            candidates.intersection(neighbors).copied().collect(),
+            excluded.intersection(neighbors).copied().collect(),
+            cliques,
+        );
+        clique.pop();
+        candidates.remove(&v);
+        excluded.insert(v);
+    }
+}
@@ -6,15 +6,26 @@
 
 pub mod astar;
 pub mod bellman_ford;
+pub mod betweenness_centrality;
 pub mod dijkstra;
 pub mod dominators;
 pub mod feedback_arc_set;
 pub mod floyd_warshall;
+pub mod girth;
+pub mod independent_set;
 pub mod isomorphism;
+pub mod johnson;
 pub mod k_shortest_path;
 pub mod matching;
+pub mod maximal_cliques;
+pub mod page_rank;
+#[cfg(feature = "rayon")]
+pub mod par_connected_components;
 pub mod simple_paths;
+#[cfg(all(feature = "rand", feature = "graphmap"))]
+pub mod spanning_tree;
 pub mod tred;
+pub mod vertex_cover;
 
 use std::collections::{BinaryHeap, HashMap};
 use std::num::NonZeroUsize;
@@ -35,16 +46,27 @@ use crate::visit::{Data, IntoNodeReferences, NodeRef};
 
 pub use astar::astar;
 pub use bellman_ford::{bellman_ford, find_negative_cycle};
-pub use dijkstra::dijkstra;
+pub use betweenness_centrality::edge_betweenness_centrality;
+pub use dijkstra::{bidirectional_dijkstra, dijkstra};
 pub use feedback_arc_set::greedy_feedback_arc_set;
 pub use floyd_warshall::floyd_warshall;
+pub use girth::girth;
+pub use independent_set::greedy_independent_set;
 pub use isomorphism::{
     is_isomorphic, is_isomorphic_matching, is_isomorphic_subgraph, is_isomorphic_subgraph_matching,
     subgraph_isomorphisms_iter,
 };
-pub use k_shortest_path::k_shortest_path;
+pub use johnson::johnson;
+pub use k_shortest_path::{k_shortest_path, k_shortest_simple_paths};
 pub use matching::{greedy_matching, maximum_matching, Matching};
+pub use maximal_cliques::maximal_cliques;
+pub use page_rank::weighted_pagerank;
+#[cfg(feature = "rayon")]
+pub use par_connected_components::par_connected_components;
 pub use simple_paths::all_simple_paths;
+#[cfg(all(feature = "rand", feature = "graphmap"))]
+pub use spanning_tree::uniform_spanning_tree;
+pub use vertex_cover::approx_vertex_cover;
 
 /// \[Generic\] Return the number of connected components of the graph.
 ///
@@ -759,6 +781,10 @@ impl<N> Cycle<N> {
 #[derive(Clone, Debug, PartialEq)]
 pub struct NegativeCycle(pub ());
 
+/// An algorithm error: the graph is not connected.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Disconnected(pub ());
+
 /// Return `true` if the graph is bipartite. A graph is bipartite if its nodes can be divided into
 /// two disjoint and indepedent sets U and V such that every edge connects U to one in V. This
 /// algorithm implements 2-coloring algorithm based on the BFS algorithm.
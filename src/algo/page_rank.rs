@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::visit::{EdgeRef, IntoEdges, IntoNodeIdentifiers, NodeIndexable};
+
+/// \[Generic\] Weighted PageRank.
+///
+/// Compute the PageRank of every node, where a random surfer leaves a node
+/// along one of its outgoing edges with probability proportional to the
+/// edge's weight, as given by `edge_weight`. Weights must be non-negative.
+///
+/// `damping` is the probability of following an edge instead of jumping to a
+/// uniformly random node (usually `0.85`). Nodes whose outgoing weights sum
+/// to zero (*dangling* nodes) spread their rank uniformly over all nodes.
+///
+/// The power iteration runs for at most `iterations` rounds and stops early
+/// once the L1 distance between two successive rank vectors drops below `tol`.
+///
+/// For an undirected graph every edge can be followed in both directions.
+///
+/// Returns a `HashMap` that maps `NodeId` to its rank; the ranks sum to `1`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::weighted_pagerank;
+/// use petgraph::graph::{node_index, DiGraph};
+/// use petgraph::visit::EdgeRef;
+///
+/// let graph = DiGraph::<(), f64>::from_edges(&[(0, 1, 1.), (1, 0, 1.), (1, 2, 9.), (2, 1, 1.)]);
+/// let rank = weighted_pagerank(&graph, 0.85, 100, 1e-10, |e| *e.weight());
+/// assert!(rank[&node_index(2)] > rank[&node_index(0)]);
+/// ```
+pub fn weighted_pagerank<G, F>(
+    graph: G,
+    damping: f64,
+    iterations: usize,
+    tol: f64,
+    mut edge_weight: F,
+) -> HashMap<G::NodeId, f64>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> f64,
+{
+    let node_count = graph.node_bound();
+    let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    if nodes.is_empty() {
+        return HashMap::new();
+    }
+    let n = nodes.len() as f64;
+
+    let mut out_edges: Vec<Vec<(usize, f64)>> = vec![Vec::new(); node_count];
+    let mut out_weight_sum = vec![0.; node_count];
+    for &node in &nodes {
+        let i = graph.to_index(node);
+        for edge in graph.edges(node) {
+            let w = edge_weight(edge);
+            out_edges[i].push((graph.to_index(edge.target()), w));
+            out_weight_sum[i] += w;
+        }
+    }
+
+    let mut rank = vec![0.; node_count];
+    for &node in &nodes {
+        rank[graph.to_index(node)] = 1. / n;
+    }
+    let mut next = vec![0.; node_count];
+    for _ in 0..iterations {
+        let dangling: f64 = nodes
+            .iter()
+            .map(|&node| graph.to_index(node))
+            .filter(|&i| out_weight_sum[i] <= 0.)
+            .map(|i| rank[i])
+            .sum();
+        let base = (1. - damping) / n + damping * dangling / n;
+        for &node in &nodes {
+            next[graph.to_index(node)] = base;
+        }
+        for &node in &nodes {
+            let i = graph.to_index(node);
+            if out_weight_sum[i] <= 0. {
+                continue;
+            }
+            for &(j, w) in &out_edges[i] {
+                next[j] += damping * rank[i] * w / out_weight_sum[i];
+            }
+        }
+        let delta: f64 = nodes
+            .iter()
+            .map(|&node| graph.to_index(node))
+            .map(|i| (next[i] - rank[i]).abs())
+            .sum();
+        std::mem::swap(&mut rank, &mut next);
+        if delta < tol {
+            break;
+        }
+    }
+
+    nodes
+        .into_iter()
+        .map(|node| (node, rank[graph.to_index(node)]))
+        .collect()
+}
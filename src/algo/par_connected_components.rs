@@ -0,0 +1,90 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use rayon::prelude::*;
+
+use crate::visit::{EdgeRef, IntoEdgeReferences, NodeCompactIndexable};
+
+/// \[Generic\] Compute the connected components of the graph in parallel.
+///
+/// For a directed graph, these are the *weakly* connected components. The
+/// edges are merged concurrently into a lock-free union-find, so the result is
+/// the same as that of [`connected_components`](fn.connected_components.html)
+/// but the work is spread over the rayon thread pool.
+///
+/// Returns the number of components, and the component of every node by node
+/// index. Components are numbered from `0` in the order of their first node.
+///
+/// Requires crate feature `rayon`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::par_connected_components;
+/// use petgraph::graph::UnGraph;
+///
+/// let mut graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (2, 3), (1, 4)]);
+/// graph.add_node(());
+/// let (count, labels) = par_connected_components(&graph);
+/// assert_eq!(count, 3);
+/// assert_eq!(labels, vec![0, 0, 1, 1, 0, 2]);
+/// ```
+pub fn par_connected_components<G>(g: G) -> (usize, Vec<usize>)
+where
+    G: NodeCompactIndexable + IntoEdgeReferences,
+{
+    let edges: Vec<(usize, usize)> = g
+        .edge_references()
+        .map(|edge| (g.to_index(edge.source()), g.to_index(edge.target())))
+        .collect();
+    let parent: Vec<AtomicUsize> = (0..g.node_bound()).map(AtomicUsize::new).collect();
+    edges.par_iter().for_each(|&(a, b)| union(&parent, a, b));
+
+    let roots: Vec<usize> = (0..parent.len())
+        .into_par_iter()
+        .map(|i| find(&parent, i))
+        .collect();
+
+    // Every root is the smallest index of its component, so it is labeled
+    // before any other node of the component.
+    let mut labels = vec![0; roots.len()];
+    let mut count = 0;
+    for (i, &root) in roots.iter().enumerate() {
+        if root == i {
+            labels[i] = count;
+            count += 1;
+        } else {
+            labels[i] = labels[root];
+        }
+    }
+    (count, labels)
+}
+
+/// Find the root of `x`, halving the path on the way.
+fn find(parent: &[AtomicUsize], mut x: usize) -> usize {
+    loop {
+        let p = parent[x].load(Ordering::Relaxed);
+        if p == x {
+            return x;
+        }
+        let gp = parent[p].load(Ordering::Relaxed);
+        let _ = parent[x].compare_exchange_weak(p, gp, Ordering::Relaxed, Ordering::Relaxed);
+        x = gp;
+    }
+}
+
+/// Merge the sets of `a` and `b`, always linking the larger root below the
+/// smaller one so that no cycle can be formed by concurrent unions.
+fn union(parent: &[AtomicUsize], a: usize, b: usize) {
+    loop {
+        let (ra, rb) = (find(parent, a), find(parent, b));
+        if ra == rb {
+            return;
+        }
+        let (low, high) = if ra < rb { (ra, rb) } else { (rb, ra) };
+        if parent[high]
+            .compare_exchange(high, low, Ordering::Relaxed, Ordering::Relaxed)
+            .is_ok()
+        {
+            return;
+        }
+    }
+}
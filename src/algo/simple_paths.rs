@@ -13,6 +13,9 @@ use crate::{
 /// Returns an iterator that produces all simple paths from `from` node to `to`, which contains at least `min_intermediate_nodes` nodes
 /// and at most `max_intermediate_nodes`, if given, or limited by the graph's order otherwise. The simple path is a path without repetitions.
 ///
+/// A path with `k` intermediate nodes has `k + 1` edges, so bounding a path to at most `max_len`
+/// edges corresponds to `max_intermediate_nodes = Some(max_len - 1)`.
+///
 /// This algorithm is adapted from <https://networkx.github.io/documentation/stable/reference/algorithms/generated/networkx.algorithms.simple_paths.all_simple_paths.html>.
 ///
 /// # Example
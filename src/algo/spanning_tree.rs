@@ -0,0 +1,105 @@
+use rand::Rng;
+
+use crate::graphmap::{NodeTrait, UnGraphMap};
+use crate::visit::{IntoNeighbors, IntoNodeIdentifiers, NodeIndexable};
+
+use super::Disconnected;
+
+/// \[Generic\] Sample a spanning tree uniformly at random.
+///
+/// Uses [Wilson's algorithm][1]: starting from every node not yet in the tree,
+/// a random walk is performed until it hits the tree, and the walk with its
+/// loops erased is added to the tree. Every spanning tree of the graph is
+/// returned with the same probability.
+///
+/// The graph is treated as undirected and must be connected, otherwise
+/// `Err(Disconnected)` is returned. Self loops are never part of the tree.
+///
+/// The result contains every node of `graph` and **|V| - 1** edges.
+///
+/// Requires crate features `rand` and `graphmap`.
+///
+/// [1]: https://doi.org/10.1145/237814.237880
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::uniform_spanning_tree;
+/// use petgraph::graphmap::UnGraphMap;
+/// use rand::{ChaChaRng, SeedableRng};
+///
+/// let graph = UnGraphMap::<_, ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (2, 3)]);
+/// let mut rng = ChaChaRng::from_seed([7; 32]);
+/// let tree = uniform_spanning_tree(&graph, &mut rng).unwrap();
+/// assert_eq!(tree.node_count(), 4);
+/// assert_eq!(tree.edge_count(), 3);
+/// assert!(tree.contains_edge(2, 3));
+/// ```
+pub fn uniform_spanning_tree<G, R>(
+    graph: G,
+    rng: &mut R,
+) -> Result<UnGraphMap<G::NodeId, ()>, Disconnected>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    G::NodeId: NodeTrait,
+    R: Rng + ?Sized,
+{
+    let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    let mut tree = UnGraphMap::with_capacity(nodes.len(), nodes.len().saturating_sub(1));
+    if nodes.is_empty() {
+        return Ok(tree);
+    }
+
+    // Undirected adjacency by node index, without self loops or parallel
+    // edges, which would bias the walk.
+    let mut adjacency = vec![Vec::new(); graph.node_bound()];
+    for &a in &nodes {
+        for b in graph.neighbors(a) {
+            let (ai, bi) = (graph.to_index(a), graph.to_index(b));
+            if ai != bi && !adjacency[ai].contains(&bi) {
+                adjacency[ai].push(bi);
+                adjacency[bi].push(ai);
+            }
+        }
+    }
+
+    // A random walk on a disconnected graph would never reach the tree.
+    let mut seen = vec![false; graph.node_bound()];
+    let mut stack = vec![graph.to_index(nodes[0])];
+    seen[stack[0]] = true;
+    let mut reached = 1;
+    while let Some(i) = stack.pop() {
+        for &j in &adjacency[i] {
+            if !seen[j] {
+                seen[j] = true;
+                reached += 1;
+                stack.push(j);
+            }
+        }
+    }
+    if reached != nodes.len() {
+        return Err(Disconnected(()));
+    }
+
+    for &n in &nodes {
+        tree.add_node(n);
+    }
+    let mut in_tree = vec![false; graph.node_bound()];
+    let mut next = vec![0; graph.node_bound()];
+    in_tree[graph.to_index(nodes[0])] = true;
+    for &start in &nodes[1..] {
+        // Random walk until the tree is hit; overwriting `next` erases loops.
+        let mut i = graph.to_index(start);
+        while !in_tree[i] {
+            let neighbors = &adjacency[i];
+            next[i] = neighbors[rng.gen_range(0, neighbors.len())];
+            i = next[i];
+        }
+        let mut i = graph.to_index(start);
+        while !in_tree[i] {
+            in_tree[i] = true;
+            tree.add_edge(graph.from_index(i), graph.from_index(next[i]), ());
+            i = next[i];
+        }
+    }
+    Ok(tree)
+}
@@ -0,0 +1,50 @@
+use crate::visit::{EdgeRef, IntoEdgeReferences, NodeIndexable};
+
+/// \[Generic\] Compute a vertex cover at most twice the size of a minimum one.
+///
+/// A vertex cover is a set of nodes such that every edge has at least one
+/// endpoint in the set. The algorithm scans the edges and, for every edge
+/// that is not yet covered, adds both of its endpoints to the cover. The
+/// chosen edges form a maximal matching, which any cover must hit once per
+/// edge, hence the factor of 2.
+///
+/// The graph is treated as undirected. A self loop is covered by its node.
+///
+/// Computes in **O(|V| + |E|)** time.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::approx_vertex_cover;
+/// use petgraph::graph::UnGraph;
+/// use petgraph::visit::EdgeRef;
+///
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+/// let cover = approx_vertex_cover(&graph);
+/// assert!(graph
+///     .edge_references()
+///     .all(|e| cover.contains(&e.source()) || cover.contains(&e.target())));
+/// ```
+pub fn approx_vertex_cover<G>(graph: G) -> Vec<G::NodeId>
+where
+    G: IntoEdgeReferences + NodeIndexable,
+{
+    let mut covered = vec![false; graph.node_bound()];
+    let mut cover = Vec::new();
+    for edge in graph.edge_references() {
+        let (a, b) = (edge.source(), edge.target());
+        let (ai, bi) = (graph.to_index(a), graph.to_index(b));
+        if covered[ai] || covered[bi] {
+            continue;
+        }
+        covered[ai] = true;
+        cover.push(a);
+        if ai != bi {
+            covered[bi] = true;
+            cover.push(b);
+        }
+    }
+    debug_assert!(graph.edge_references().all(
+        |edge| covered[graph.to_index(edge.source())] || covered[graph.to_index(edge.target())]
+    ));
+    cover
+}
@@ -17,7 +17,7 @@ use crate::util::enumerate;
 use crate::visit;
 
 #[cfg(feature = "serde-1")]
-mod serialization;
+pub(crate) mod serialization;
 
 /// The default integer type for graph indices.
 /// `u32` is the default to reduce the size of the graph's data and improve
@@ -204,7 +204,9 @@ where
     }))
 }
 
-fn deser_graph_node_holes<'de, D, Ix>(deserializer: D) -> Result<Vec<NodeIndex<Ix>>, D::Error>
+pub(crate) fn deser_graph_node_holes<'de, D, Ix>(
+    deserializer: D,
+) -> Result<Vec<NodeIndex<Ix>>, D::Error>
 where
     D: Deserializer<'de>,
     Ix: IndexType + Deserialize<'de>,
@@ -216,7 +218,9 @@ where
     )
 }
 
-fn deser_graph_edges<'de, D, N, Ix>(deserializer: D) -> Result<Vec<Edge<N, Ix>>, D::Error>
+pub(crate) fn deser_graph_edges<'de, D, N, Ix>(
+    deserializer: D,
+) -> Result<Vec<Edge<N, Ix>>, D::Error>
 where
     D: Deserializer<'de>,
     N: Deserialize<'de>,
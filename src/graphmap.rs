@@ -1,15 +1,17 @@
 //! `GraphMap<N, E, Ty>` is a graph datastructure where node values are mapping
 //! keys.
 
-use indexmap::map::Keys;
+use indexmap::map::{Entry, Keys, Values, ValuesMut};
 use indexmap::map::{Iter as IndexMapIter, IterMut as IndexMapIterMut};
 use indexmap::IndexMap;
 use std::cmp::Ordering;
-use std::collections::HashSet;
+use std::collections::hash_map::RandomState;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::convert::TryFrom;
 use std::fmt;
-use std::hash::{self, Hash};
-use std::iter::FromIterator;
+use std::hash::{self, BuildHasher, Hash};
 use std::iter::{Cloned, DoubleEndedIterator};
+use std::iter::{FromIterator, Sum};
 use std::marker::PhantomData;
 use std::mem;
 use std::ops::{Deref, Index, IndexMut};
@@ -17,13 +19,29 @@ use std::slice::Iter;
 
 use crate::{Directed, Direction, EdgeType, Incoming, Outgoing, Undirected};
 
+use crate::algo::Measure;
 use crate::graph::node_index;
 use crate::graph::Graph;
+#[cfg(feature = "serde-1")]
+use crate::graph::{Edge, IndexType, NodeIndex};
+#[cfg(feature = "serde-1")]
+use crate::graph_impl::serialization::{
+    deser_graph_edges, deser_graph_node_holes, invalid_node_err, EdgeProperty,
+};
+#[cfg(feature = "serde-1")]
+use crate::serde_utils::{CollectSeqWithLength, FromDeserialized};
+#[cfg(feature = "stable_graph")]
+use crate::stable_graph::StableGraph;
+use crate::unionfind::UnionFind;
 use crate::visit;
 use crate::IntoWeightedEdge;
+#[cfg(feature = "bincode")]
+use bincode::Options;
+#[cfg(feature = "serde-1")]
+use std::cmp;
 
 #[cfg(feature = "rayon")]
-use indexmap::map::rayon::ParKeys;
+use indexmap::map::rayon::{ParIter, ParKeys};
 #[cfg(feature = "rayon")]
 use rayon::{iter::plumbing::UnindexedConsumer, prelude::*};
 
@@ -31,14 +49,14 @@ use rayon::{iter::plumbing::UnindexedConsumer, prelude::*};
 ///
 /// For example, an edge between *1* and *2* is equivalent to an edge between
 /// *2* and *1*.
-pub type UnGraphMap<N, E> = GraphMap<N, E, Undirected>;
+pub type UnGraphMap<N, E, S = RandomState> = GraphMap<N, E, Undirected, S>;
 /// A `GraphMap` with directed edges.
 ///
 /// For example, an edge from *1* to *2* is distinct from an edge from *2* to
 /// *1*.
-pub type DiGraphMap<N, E> = GraphMap<N, E, Directed>;
+pub type DiGraphMap<N, E, S = RandomState> = GraphMap<N, E, Directed, S>;
 
-/// `GraphMap<N, E, Ty>` is a graph datastructure using an associative array
+/// `GraphMap<N, E, Ty, S>` is a graph datastructure using an associative array
 /// of its node weights `N`.
 ///
 /// It uses an combined adjacency list and sparse adjacency matrix
@@ -56,6 +74,10 @@ pub type DiGraphMap<N, E> = GraphMap<N, E, Directed>;
 /// - `E` can be of arbitrary type.
 /// - Edge type `Ty` that determines whether the graph edges are directed or
 /// undirected.
+/// - Hasher `S` used for the node and edge maps, which defaults to the
+///   standard library's `RandomState`. Graphs with another hasher are created
+///   with [`with_hasher`](#method.with_hasher), or with `default`,
+///   [`from_edges`](#method.from_edges) and `collect` if `S: Default`.
 ///
 /// You can use the type aliases `UnGraphMap` and `DiGraphMap` for convenience.
 ///
@@ -63,18 +85,46 @@ pub type DiGraphMap<N, E> = GraphMap<N, E, Directed>;
 ///
 /// Depends on crate feature `graphmap` (default).
 #[derive(Clone)]
-pub struct GraphMap<N, E, Ty> {
-    nodes: IndexMap<N, Vec<(N, CompactDirection)>>,
-    edges: IndexMap<(N, N), E>,
+pub struct GraphMap<N, E, Ty, S = RandomState> {
+    nodes: IndexMap<N, Vec<(N, CompactDirection)>, S>,
+    edges: IndexMap<(N, N), E, S>,
     ty: PhantomData<Ty>,
 }
 
-impl<N: Eq + Hash + fmt::Debug, E: fmt::Debug, Ty: EdgeType> fmt::Debug for GraphMap<N, E, Ty> {
+impl<N: Eq + Hash + fmt::Debug, E: fmt::Debug, Ty: EdgeType, S> fmt::Debug
+    for GraphMap<N, E, Ty, S>
+{
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         self.nodes.fmt(f)
     }
 }
 
+/// Hash the set of nodes and the set of edges with their weights,
+/// independently of the order they were inserted in.
+///
+/// Graphs with the same nodes and the same weighted edges hash the same. For
+/// undirected graphs, an edge hashes the same no matter the order of its
+/// endpoints.
+///
+/// Computes in **O(|V| log |V| + |E| log |E|)** time, due to sorting the nodes
+/// and edges.
+impl<N, E, Ty, S> Hash for GraphMap<N, E, Ty, S>
+where
+    N: NodeTrait,
+    E: Hash,
+    Ty: EdgeType,
+{
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        let mut nodes = self.nodes.keys().collect::<Vec<_>>();
+        nodes.sort();
+        nodes.hash(state);
+        // edge keys are unique, so the weights don't need to be compared
+        let mut edges = self.edges.iter().collect::<Vec<_>>();
+        edges.sort_by_key(|&(key, _)| key);
+        edges.hash(state);
+    }
+}
+
 /// A trait group for `GraphMap`'s node identifier.
 pub trait NodeTrait: Copy + Ord + Hash {}
 impl<N> NodeTrait for N where N: Copy + Ord + Hash {}
@@ -122,47 +172,374 @@ impl PartialEq<Direction> for CompactDirection {
 }
 
 #[cfg(feature = "serde-1")]
-impl<N, E, Ty> serde::Serialize for GraphMap<N, E, Ty>
+impl<N, E, Ty, S> serde::Serialize for GraphMap<N, E, Ty, S>
 where
     Ty: EdgeType,
     N: NodeTrait + serde::Serialize,
     E: serde::Serialize,
-    GraphMap<N, E, Ty>: Clone,
+    S: BuildHasher,
 {
     /// Serializes the given `GraphMap` into the same format as the standard
     /// `Graph`. Needs feature `serde-1`.
-    ///
-    /// Note: the graph has to be `Clone` for this to work.
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    fn serialize<Ser>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error>
+    where
+        Ser: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        // Keep in sync with the serialization of Graph<N, E, Ty, u32>
+        let node_holes: &[u32] = &[];
+        let mut graph = serializer.serialize_struct("Graph", 4)?;
+        graph.serialize_field("nodes", &SerNodes(&self.nodes))?;
+        graph.serialize_field("node_holes", node_holes)?;
+        graph.serialize_field("edge_property", &EdgeProperty::from(self.ty))?;
+        graph.serialize_field("edges", &SerEdges(self))?;
+        graph.end()
+    }
+}
+
+/// Serializes the nodes of a `GraphMap` as a sequence.
+#[cfg(feature = "serde-1")]
+struct SerNodes<'a, N, S>(&'a IndexMap<N, Vec<(N, CompactDirection)>, S>);
+
+#[cfg(feature = "serde-1")]
+impl<'a, N, S> serde::Serialize for SerNodes<'a, N, S>
+where
+    N: NodeTrait + serde::Serialize,
+{
+    fn serialize<Ser>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error>
+    where
+        Ser: serde::Serializer,
+    {
+        serializer.collect_seq_exact(self.0.keys())
+    }
+}
+
+/// Serializes the edges of a `GraphMap` as a sequence of
+/// `Some((source index, target index, weight))`, like those of a `Graph`.
+#[cfg(feature = "serde-1")]
+struct SerEdges<'a, N, E, Ty, S>(&'a GraphMap<N, E, Ty, S>);
+
+#[cfg(feature = "serde-1")]
+impl<'a, N, E, Ty, S> serde::Serialize for SerEdges<'a, N, E, Ty, S>
+where
+    N: NodeTrait,
+    E: serde::Serialize,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    fn serialize<Ser>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error>
+    where
+        Ser: serde::Serializer,
+    {
+        let nodes = &self.0.nodes;
+        if nodes.len() >= <u32 as IndexType>::max().index() {
+            return Err(serde::ser::Error::custom(format_args!(
+                "invalid size: graph node count {} exceeds index type maximum {}",
+                nodes.len(),
+                <u32 as IndexType>::max().index()
+            )));
+        }
+        serializer.collect_seq_exact(self.0.edges.iter().map(|(&(a, b), weight)| {
+            let ai = nodes.get_index_of(&a).unwrap() as u32;
+            let bi = nodes.get_index_of(&b).unwrap() as u32;
+            Some((ai, bi, weight))
+        }))
+    }
+}
+
+/// Serializes the edges of a `GraphMap` as a sequence of
+/// `(source index, target index, weight)`, for [`GraphMap::to_bytes`].
+#[cfg(feature = "bincode")]
+struct SerEdgeIndices<'a, N, E, Ty, S>(&'a GraphMap<N, E, Ty, S>);
+
+#[cfg(feature = "bincode")]
+impl<'a, N, E, Ty, S> serde::Serialize for SerEdgeIndices<'a, N, E, Ty, S>
+where
+    N: NodeTrait,
+    E: serde::Serialize,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    fn serialize<Ser>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error>
     where
-        S: serde::Serializer,
+        Ser: serde::Serializer,
     {
-        let cloned_graph: GraphMap<N, E, Ty> = GraphMap::clone(self);
-        let equivalent_graph: Graph<N, E, Ty, u32> = cloned_graph.into_graph();
-        equivalent_graph.serialize(serializer)
+        let nodes = &self.0.nodes;
+        serializer.collect_seq_exact(self.0.edges.iter().map(|(&(a, b), weight)| {
+            let ai = nodes.get_index_of(&a).unwrap();
+            let bi = nodes.get_index_of(&b).unwrap();
+            (ai, bi, weight)
+        }))
     }
 }
 
+/// Deserialization representation for `GraphMap`, in the format of
+/// `Graph<N, E, Ty, u32>`.
 #[cfg(feature = "serde-1")]
-impl<'de, N, E, Ty> serde::Deserialize<'de> for GraphMap<N, E, Ty>
+#[derive(Deserialize)]
+#[serde(rename = "Graph")]
+#[serde(bound(deserialize = "N: serde::Deserialize<'de>, E: serde::Deserialize<'de>"))]
+struct DeserGraphMap<N, E> {
+    nodes: Vec<N>,
+    #[serde(deserialize_with = "deser_graph_node_holes")]
+    #[allow(unused)]
+    #[serde(default = "Vec::new")]
+    node_holes: Vec<NodeIndex<u32>>,
+    edge_property: EdgeProperty,
+    #[serde(deserialize_with = "deser_graph_edges")]
+    edges: Vec<Edge<E, u32>>,
+}
+
+#[cfg(feature = "serde-1")]
+impl<'de, N, E, Ty, S> serde::Deserialize<'de> for GraphMap<N, E, Ty, S>
 where
     Ty: EdgeType,
     N: NodeTrait + serde::Deserialize<'de>,
-    E: Clone + serde::Deserialize<'de>,
+    E: serde::Deserialize<'de>,
+    S: BuildHasher + Default,
 {
     /// Deserializes into a new `GraphMap` from the same format as the standard
     /// `Graph`. Needs feature `serde-1`.
     ///
     /// **Warning**: When deseralizing a graph that was not originally a `GraphMap`,
     /// the restrictions from [`from_graph`](#method.from_graph) apply.
-    ///
-    /// Note: The edge weights have to be `Clone` for this to work.
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        let equivalent_graph: Graph<N, E, Ty, u32> = Graph::deserialize(deserializer)?;
-        Ok(GraphMap::from_graph(equivalent_graph))
+        let input = DeserGraphMap::<N, E>::deserialize(deserializer)?;
+        let ty = PhantomData::<Ty>::from_deserialized(input.edge_property)?;
+        let nodes = input.nodes;
+        let mut graph = GraphMap::with_capacity_default(nodes.len(), input.edges.len());
+        for &n in &nodes {
+            graph.add_node(n);
+        }
+        for edge in input.edges {
+            let (ai, bi) = (edge.source().index(), edge.target().index());
+            match (nodes.get(ai), nodes.get(bi)) {
+                (Some(&a), Some(&b)) => {
+                    graph.add_edge(a, b, edge.weight);
+                }
+                _ => return Err(invalid_node_err(cmp::max(ai, bi), nodes.len())),
+            }
+        }
+        graph.ty = ty;
+        Ok(graph)
+    }
+}
+
+/// A wrapper that serializes a `GraphMap` as a compact edge list.
+///
+/// The default serialization of `GraphMap` uses the format of `Graph`, with
+/// edges referring to nodes by index. `AsEdgeList` instead serializes the
+/// graph as a list of `(a, b, weight)` edges together with the list of nodes
+/// that have no edges, which is smaller and easier to read in formats like
+/// JSON:
+///
+/// ```text
+/// EdgeList {
+///     nodes: [N],
+///     edges: [(N, N, E)],
+/// }
+/// ```
+///
+/// Serialize `AsEdgeList(&graph)` or `AsEdgeList(graph)`, and deserialize
+/// `AsEdgeList<GraphMap<N, E, Ty>>`. The order of the edges is preserved.
+/// After deserializing, the nodes with edges come first, in the order of
+/// their first edge, followed by the nodes without edges. The edge type is
+/// not part of the format.
+///
+/// Needs feature `serde-1`.
+///
+/// ```
+/// use petgraph::graphmap::{AsEdgeList, DiGraphMap};
+///
+/// let mut g = DiGraphMap::<_, u8>::from_edges(&[(1, 2, 7)]);
+/// g.add_node(3);
+/// let bytes = bincode::serialize(&AsEdgeList(&g)).unwrap();
+/// let AsEdgeList(h): AsEdgeList<DiGraphMap<i32, u8>> = bincode::deserialize(&bytes).unwrap();
+/// assert_eq!(h.edge_weight(1, 2), Some(&7));
+/// assert!(h.contains_node(3));
+/// ```
+#[cfg(feature = "serde-1")]
+#[derive(Clone, Debug)]
+pub struct AsEdgeList<G>(pub G);
+
+#[cfg(feature = "serde-1")]
+impl<N, E, Ty, S> serde::Serialize for AsEdgeList<&GraphMap<N, E, Ty, S>>
+where
+    Ty: EdgeType,
+    N: NodeTrait + serde::Serialize,
+    E: serde::Serialize,
+    S: BuildHasher,
+{
+    fn serialize<Ser>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error>
+    where
+        Ser: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let mut list = serializer.serialize_struct("EdgeList", 2)?;
+        list.serialize_field("nodes", &SerIsolatedNodes(&self.0.nodes))?;
+        list.serialize_field("edges", &SerEdgeList(&self.0.edges))?;
+        list.end()
+    }
+}
+
+#[cfg(feature = "serde-1")]
+impl<N, E, Ty, S> serde::Serialize for AsEdgeList<GraphMap<N, E, Ty, S>>
+where
+    Ty: EdgeType,
+    N: NodeTrait + serde::Serialize,
+    E: serde::Serialize,
+    S: BuildHasher,
+{
+    fn serialize<Ser>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error>
+    where
+        Ser: serde::Serializer,
+    {
+        AsEdgeList(&self.0).serialize(serializer)
+    }
+}
+
+/// Serializes the nodes without edges of a `GraphMap` as a sequence.
+#[cfg(feature = "serde-1")]
+struct SerIsolatedNodes<'a, N, S>(&'a IndexMap<N, Vec<(N, CompactDirection)>, S>);
+
+#[cfg(feature = "serde-1")]
+impl<'a, N, S> serde::Serialize for SerIsolatedNodes<'a, N, S>
+where
+    N: NodeTrait + serde::Serialize,
+{
+    fn serialize<Ser>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error>
+    where
+        Ser: serde::Serializer,
+    {
+        let isolated = self
+            .0
+            .iter()
+            .filter(|(_, neighbors)| neighbors.is_empty())
+            .map(|(n, _)| n);
+        serializer.collect_seq_with_length(isolated.clone().count(), isolated)
+    }
+}
+
+/// Serializes the edges of a `GraphMap` as a sequence of `(a, b, weight)`.
+#[cfg(feature = "serde-1")]
+struct SerEdgeList<'a, N, E, S>(&'a IndexMap<(N, N), E, S>);
+
+#[cfg(feature = "serde-1")]
+impl<'a, N, E, S> serde::Serialize for SerEdgeList<'a, N, E, S>
+where
+    N: NodeTrait + serde::Serialize,
+    E: serde::Serialize,
+{
+    fn serialize<Ser>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error>
+    where
+        Ser: serde::Serializer,
+    {
+        serializer.collect_seq_exact(self.0.iter().map(|(&(a, b), weight)| (a, b, weight)))
+    }
+}
+
+/// Deserialization representation for `AsEdgeList`.
+#[cfg(feature = "serde-1")]
+#[derive(Deserialize)]
+#[serde(rename = "EdgeList")]
+#[serde(bound(deserialize = "N: serde::Deserialize<'de>, E: serde::Deserialize<'de>"))]
+struct DeserEdgeList<N, E> {
+    nodes: Vec<N>,
+    edges: Vec<(N, N, E)>,
+}
+
+#[cfg(feature = "serde-1")]
+impl<'de, N, E, Ty, S> serde::Deserialize<'de> for AsEdgeList<GraphMap<N, E, Ty, S>>
+where
+    Ty: EdgeType,
+    N: NodeTrait + serde::Deserialize<'de>,
+    E: serde::Deserialize<'de>,
+    S: BuildHasher + Default,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let input = DeserEdgeList::<N, E>::deserialize(deserializer)?;
+        let mut graph = GraphMap::with_capacity_default(input.nodes.len(), input.edges.len());
+        for (a, b, weight) in input.edges {
+            graph.add_edge(a, b, weight);
+        }
+        for n in input.nodes {
+            graph.add_node(n);
+        }
+        Ok(AsEdgeList(graph))
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<N, E, Ty, S> GraphMap<N, E, Ty, S>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher + Default,
+{
+    /// Encode the graph in a compact binary format, for use with
+    /// [`from_bytes`](#method.from_bytes). Needs feature `bincode`.
+    ///
+    /// The encoding holds the nodes in order followed by the edges, with their
+    /// endpoints given by node index. Unlike the serde implementation, it does
+    /// not go through `Graph` and does not require the graph to be `Clone`.
+    ///
+    /// Returns an error if a node or edge weight fails to serialize.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, bincode::Error>
+    where
+        N: serde::Serialize,
+        E: serde::Serialize,
+    {
+        bincode::DefaultOptions::new().serialize(&(SerNodes(&self.nodes), SerEdgeIndices(self)))
+    }
+
+    /// Decode a graph encoded with [`to_bytes`](#method.to_bytes). Needs
+    /// feature `bincode`.
+    ///
+    /// Returns an error if `bytes` is not a valid encoding, e.g. if an edge
+    /// endpoint is out of bounds.
+    ///
+    /// ```
+    /// use petgraph::graphmap::DiGraphMap;
+    ///
+    /// let g = DiGraphMap::<u32, f32>::from_edges(&[(1, 2, 0.5), (2, 3, 1.5)]);
+    /// let bytes = g.to_bytes().unwrap();
+    /// let h = DiGraphMap::<u32, f32>::from_bytes(&bytes).unwrap();
+    /// assert_eq!(h.nodes().collect::<Vec<_>>(), vec![1, 2, 3]);
+    /// assert_eq!(h.edge_weight(2, 3), Some(&1.5));
+    /// ```
+    pub fn from_bytes<'de>(bytes: &'de [u8]) -> Result<Self, bincode::Error>
+    where
+        N: serde::Deserialize<'de>,
+        E: serde::Deserialize<'de>,
+    {
+        let (nodes, edges): (Vec<N>, Vec<(usize, usize, E)>) =
+            bincode::DefaultOptions::new().deserialize(bytes)?;
+        let mut graph = GraphMap::with_capacity_default(nodes.len(), edges.len());
+        for &n in &nodes {
+            graph.add_node(n);
+        }
+        for (a, b, weight) in edges {
+            match (nodes.get(a), nodes.get(b)) {
+                (Some(&a), Some(&b)) => {
+                    graph.add_edge(a, b, weight);
+                }
+                _ => {
+                    return Err(Box::new(bincode::ErrorKind::Custom(format!(
+                        "edge endpoint out of bounds: ({}, {})",
+                        a, b
+                    ))))
+                }
+            }
+        }
+        Ok(graph)
     }
 }
 
@@ -178,31 +555,93 @@ where
 
     /// Create a new `GraphMap` with estimated capacity.
     pub fn with_capacity(nodes: usize, edges: usize) -> Self {
-        GraphMap {
-            nodes: IndexMap::with_capacity(nodes),
-            edges: IndexMap::with_capacity(edges),
-            ty: PhantomData,
-        }
+        Self::with_capacity_default(nodes, edges)
     }
+}
 
-    /// Return the current node and edge capacity of the graph.
-    pub fn capacity(&self) -> (usize, usize) {
-        (self.nodes.capacity(), self.edges.capacity())
-    }
+/// An error from [`GraphMap::relabel_node`](struct.GraphMap.html#method.relabel_node).
+#[derive(Clone, Debug, PartialEq)]
+pub enum RelabelError {
+    /// The node to relabel is not in the graph.
+    NodeNotFound,
+    /// The new label is already a node in the graph.
+    NodeExists,
+}
 
-    /// Use their natural order to map the node pair (a, b) to a canonical edge id.
-    #[inline]
-    fn edge_key(a: N, b: N) -> (N, N) {
-        if Ty::is_directed() || a <= b {
-            (a, b)
-        } else {
-            (b, a)
+/// An error: the adjacency matrix is not square, or its size does not match
+/// the number of node labels.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InvalidAdjacencyMatrix(pub ());
+
+impl<N, Ty> GraphMap<N, (), Ty>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+{
+    /// Create a new `GraphMap` from a boolean adjacency matrix.
+    ///
+    /// The graph has one node per label, in order, and an edge from
+    /// `labels[i]` to `labels[j]` wherever `matrix[i][j]` is `true`. For
+    /// undirected graphs, an edge is added if either `matrix[i][j]` or
+    /// `matrix[j][i]` is `true`, so both a symmetric matrix and one of its
+    /// triangles give the same graph. Duplicate labels are merged into one
+    /// node.
+    ///
+    /// Return an error if `matrix` is not a square matrix with
+    /// `labels.len()` rows.
+    ///
+    /// ```
+    /// use petgraph::graphmap::DiGraphMap;
+    ///
+    /// let matrix = vec![
+    ///     vec![false, true, false],
+    ///     vec![false, false, true],
+    ///     vec![true, false, false],
+    /// ];
+    /// let g = DiGraphMap::from_adjacency_matrix(&['a', 'b', 'c'], &matrix).unwrap();
+    /// assert_eq!(g.edge_count(), 3);
+    /// assert!(g.contains_edge('c', 'a'));
+    /// ```
+    pub fn from_adjacency_matrix(
+        labels: &[N],
+        matrix: &[Vec<bool>],
+    ) -> Result<Self, InvalidAdjacencyMatrix> {
+        let n = labels.len();
+        if matrix.len() != n || matrix.iter().any(|row| row.len() != n) {
+            return Err(InvalidAdjacencyMatrix(()));
         }
+        let mut graph = Self::with_capacity(n, 0);
+        for &label in labels {
+            graph.add_node(label);
+        }
+        for (row, &a) in matrix.iter().zip(labels) {
+            for (&edge, &b) in row.iter().zip(labels) {
+                if edge {
+                    graph.add_edge(a, b, ());
+                }
+            }
+        }
+        Ok(graph)
     }
+}
 
-    /// Whether the graph has directed edges.
-    pub fn is_directed(&self) -> bool {
-        Ty::is_directed()
+impl<N, E, Ty, S> GraphMap<N, E, Ty, S>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    /// Create a new `GraphMap` with estimated capacity, using the default
+    /// value of `S` as the hasher.
+    pub(crate) fn with_capacity_default(nodes: usize, edges: usize) -> Self
+    where
+        S: Default,
+    {
+        GraphMap {
+            nodes: IndexMap::with_capacity_and_hasher(nodes, S::default()),
+            edges: IndexMap::with_capacity_and_hasher(edges, S::default()),
+            ty: PhantomData,
+        }
     }
 
     /// Create a new `GraphMap` from an iterable of edges.
@@ -228,10 +667,91 @@ where
     where
         I: IntoIterator,
         I::Item: IntoWeightedEdge<E, NodeId = N>,
+        S: Default,
     {
         Self::from_iter(iterable)
     }
 
+    /// Create a new `GraphMap` that uses `hasher` to hash its nodes and edges.
+    ///
+    /// The hasher is cloned so that the node and edge maps each own one.
+    pub fn with_hasher(hasher: S) -> Self
+    where
+        S: Clone,
+    {
+        Self::with_capacity_and_hasher(0, 0, hasher)
+    }
+
+    /// Create a new `GraphMap` with estimated capacity, using `hasher` to
+    /// hash its nodes and edges.
+    pub fn with_capacity_and_hasher(nodes: usize, edges: usize, hasher: S) -> Self
+    where
+        S: Clone,
+    {
+        GraphMap {
+            nodes: IndexMap::with_capacity_and_hasher(nodes, hasher.clone()),
+            edges: IndexMap::with_capacity_and_hasher(edges, hasher),
+            ty: PhantomData,
+        }
+    }
+
+    /// Return a reference to the graph's hasher.
+    pub fn hasher(&self) -> &S {
+        self.edges.hasher()
+    }
+
+    /// Return the current node and edge capacity of the graph.
+    pub fn capacity(&self) -> (usize, usize) {
+        (self.nodes.capacity(), self.edges.capacity())
+    }
+
+    /// Reserve capacity for at least `additional` more nodes to be inserted
+    /// in the graph.
+    pub fn reserve_nodes(&mut self, additional: usize) {
+        self.nodes.reserve(additional);
+    }
+
+    /// Reserve capacity for at least `additional` more edges to be inserted
+    /// in the graph.
+    pub fn reserve_edges(&mut self, additional: usize) {
+        self.edges.reserve(additional);
+    }
+
+    /// Shrink the capacity of the graph, including the adjacency lists of
+    /// all nodes, as much as possible.
+    ///
+    /// ```
+    /// use petgraph::graphmap::DiGraphMap;
+    ///
+    /// let mut g = DiGraphMap::<u32, ()>::with_capacity(100, 1000);
+    /// g.add_edge(0, 1, ());
+    /// assert!(g.capacity() >= (100, 1000));
+    /// g.shrink_to_fit();
+    /// assert!(g.capacity() < (100, 1000));
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.nodes.shrink_to_fit();
+        self.edges.shrink_to_fit();
+        for links in self.nodes.values_mut() {
+            links.shrink_to_fit();
+        }
+    }
+
+    /// Use their natural order to map the node pair (a, b) to a canonical edge id.
+    #[inline]
+    pub(crate) fn edge_key(a: N, b: N) -> (N, N) {
+        if Ty::is_directed() || a <= b {
+            (a, b)
+        } else {
+            (b, a)
+        }
+    }
+
+    /// Whether the graph has directed edges.
+    pub fn is_directed(&self) -> bool {
+        Ty::is_directed()
+    }
+
     /// Return the number of nodes in the graph.
     pub fn node_count(&self) -> usize {
         self.nodes.len()
@@ -242,18 +762,70 @@ where
         self.edges.len()
     }
 
+    /// Return `true` if the graph has no nodes.
+    ///
+    /// A graph with nodes but no edges is not empty; use
+    /// [`has_edges`](#method.has_edges) to check for edges.
+    ///
+    /// ```
+    /// use petgraph::graphmap::UnGraphMap;
+    ///
+    /// let mut g = UnGraphMap::<_, ()>::new();
+    /// assert!(g.is_empty());
+    /// g.add_node(1);
+    /// assert!(!g.is_empty());
+    /// assert!(!g.has_edges());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Return `true` if the graph has at least one edge.
+    pub fn has_edges(&self) -> bool {
+        !self.edges.is_empty()
+    }
+
     /// Remove all nodes and edges
     pub fn clear(&mut self) {
         self.nodes.clear();
         self.edges.clear();
     }
 
+    /// Remove all edges, keeping the nodes and their order.
+    pub fn clear_edges(&mut self) {
+        self.edges.clear();
+        for links in self.nodes.values_mut() {
+            links.clear();
+        }
+    }
+
     /// Add node `n` to the graph.
     pub fn add_node(&mut self, n: N) -> N {
         self.nodes.entry(n).or_insert(Vec::new());
         n
     }
 
+    /// Add node `n` to the graph if it isn't already part of it.
+    ///
+    /// Return the node, and `true` if it was newly inserted.
+    ///
+    /// ```
+    /// use petgraph::graphmap::UnGraphMap;
+    ///
+    /// let mut g = UnGraphMap::<_, ()>::new();
+    /// assert_eq!(g.get_or_add_node("a"), ("a", true));
+    /// assert_eq!(g.get_or_add_node("a"), ("a", false));
+    /// ```
+    pub fn get_or_add_node(&mut self, n: N) -> (N, bool) {
+        match self.nodes.entry(n) {
+            Entry::Occupied(entry) => (*entry.key(), false),
+            Entry::Vacant(entry) => {
+                entry.insert(Vec::new());
+                (n, true)
+            }
+        }
+    }
+
     /// Return `true` if node `n` was removed.
     ///
     /// Computes in **O(V)** time, due to the removal of edges with other nodes.
@@ -276,11 +848,99 @@ where
         true
     }
 
+    /// Return `true` if node `n` was removed.
+    ///
+    /// Unlike [`remove_node`](#method.remove_node), this preserves the order
+    /// of the remaining nodes and edges, so the `NodeIndexable` and
+    /// `EdgeIndexable` indices of nodes before `n` do not change, and those
+    /// after it are shifted down by one.
+    ///
+    /// Computes in **O(|V| + |E|)** time, due to shifting the nodes and
+    /// edges after the removed ones.
+    ///
+    /// ```
+    /// use petgraph::graphmap::UnGraphMap;
+    ///
+    /// let mut g = UnGraphMap::<_, ()>::from_edges(&[(1, 2), (2, 3), (3, 4)]);
+    /// g.remove_node_stable(2);
+    /// assert_eq!(g.nodes().collect::<Vec<_>>(), vec![1, 3, 4]);
+    /// ```
+    pub fn remove_node_stable(&mut self, n: N) -> bool {
+        let links = match self.nodes.shift_remove(&n) {
+            None => return false,
+            Some(sus) => sus,
+        };
+        if links.is_empty() {
+            return true;
+        }
+        for (succ, dir) in links {
+            self.remove_single_edge(&succ, &n, dir.opposite());
+        }
+        // a single pass keeps this linear, unlike a shift_remove per edge
+        self.edges.retain(|&(a, b), _| a != n && b != n);
+        true
+    }
+
+    /// Keep all nodes for which `f` returns `true`, and remove the others
+    /// together with their edges.
+    ///
+    /// Unlike calling [`remove_node`](#method.remove_node) in a loop, the
+    /// adjacency lists are cleaned up in a single pass, and the order of the
+    /// remaining nodes and edges is preserved.
+    ///
+    /// Computes in **O(|V| + |E|)** time (average).
+    ///
+    /// ```
+    /// use petgraph::graphmap::UnGraphMap;
+    ///
+    /// let mut g = UnGraphMap::<_, ()>::from_edges(&[(1, 2), (2, 3), (3, 4)]);
+    /// g.retain_nodes(|n| n != 3);
+    /// assert_eq!(g.nodes().collect::<Vec<_>>(), vec![1, 2, 4]);
+    /// assert_eq!(g.edge_count(), 1);
+    /// ```
+    pub fn retain_nodes<F>(&mut self, mut f: F)
+    where
+        F: FnMut(N) -> bool,
+    {
+        let mut removed = HashSet::new();
+        self.nodes.retain(|&n, _| {
+            let keep = f(n);
+            if !keep {
+                removed.insert(n);
+            }
+            keep
+        });
+        if removed.is_empty() {
+            return;
+        }
+        self.edges
+            .retain(|(a, b), _| !removed.contains(a) && !removed.contains(b));
+        for links in self.nodes.values_mut() {
+            links.retain(|(m, _)| !removed.contains(m));
+        }
+    }
+
     /// Return `true` if the node is contained in the graph.
     pub fn contains_node(&self, n: N) -> bool {
         self.nodes.contains_key(&n)
     }
 
+    /// Return the index of node `n`, i.e. its position in the order of
+    /// [`nodes`](#method.nodes), or `None` if the node doesn't exist.
+    ///
+    /// This is the index used by the `NodeIndexable` implementation.
+    ///
+    /// ```
+    /// use petgraph::graphmap::UnGraphMap;
+    ///
+    /// let g = UnGraphMap::<_, ()>::from_edges(&[("a", "b")]);
+    /// assert_eq!(g.node_index("b"), Some(1));
+    /// assert_eq!(g.node_index("c"), None);
+    /// ```
+    pub fn node_index(&self, n: N) -> Option<usize> {
+        self.nodes.get_index_of(&n)
+    }
+
     /// Add an edge connecting `a` and `b` to the graph, with associated
     /// data `weight`. For a directed graph, the edge is directed from `a`
     /// to `b`.
@@ -307,21 +967,116 @@ where
             old
         } else {
             // insert in the adjacency list if it's a new edge
-            self.nodes
-                .entry(a)
-                .or_insert_with(|| Vec::with_capacity(1))
-                .push((b, CompactDirection::Outgoing));
-            if a != b {
-                // self loops don't have the Incoming entry
-                self.nodes
-                    .entry(b)
-                    .or_insert_with(|| Vec::with_capacity(1))
-                    .push((a, CompactDirection::Incoming));
-            }
+            Self::add_links(&mut self.nodes, a, b);
             None
         }
     }
 
+    /// Add all edges in `edges` to the graph, as if by calling
+    /// [`add_edge`](#method.add_edge) on each of them in order, and return
+    /// the number of edges that did not exist before.
+    ///
+    /// Capacity for the edges is reserved once up front.
+    ///
+    /// ```
+    /// use petgraph::graphmap::UnGraphMap;
+    ///
+    /// let mut g = UnGraphMap::new();
+    /// assert_eq!(g.add_edges(&[(1, 2, 'a'), (2, 3, 'b'), (2, 1, 'c')]), 2);
+    /// assert_eq!(g.edge_weight(1, 2), Some(&'c'));
+    /// ```
+    pub fn add_edges(&mut self, edges: &[(N, N, E)]) -> usize
+    where
+        E: Clone,
+    {
+        self.edges.reserve(edges.len());
+        let mut added = 0;
+        for &(a, b, ref weight) in edges {
+            if self.add_edge(a, b, weight.clone()).is_none() {
+                added += 1;
+            }
+        }
+        added
+    }
+
+    /// Add an edge connecting `a` and `b` with weight `weight`, or if the edge
+    /// already exists, merge `weight` into its weight with `combine`.
+    ///
+    /// Return `true` if the edge was newly added.
+    ///
+    /// Inserts nodes `a` and/or `b` if they aren't already part of the graph.
+    ///
+    /// ```
+    /// use petgraph::graphmap::DiGraphMap;
+    ///
+    /// let mut g = DiGraphMap::new();
+    /// assert!(g.update_edge("x", "y", 1, |w, new| *w += new));
+    /// assert!(!g.update_edge("x", "y", 2, |w, new| *w += new));
+    /// assert_eq!(g.edge_weight("x", "y"), Some(&3));
+    /// ```
+    pub fn update_edge<F>(&mut self, a: N, b: N, weight: E, combine: F) -> bool
+    where
+        F: FnOnce(&mut E, E),
+    {
+        match self.edges.get_mut(&Self::edge_key(a, b)) {
+            Some(existing) => {
+                combine(existing, weight);
+                false
+            }
+            None => {
+                self.add_edge(a, b, weight);
+                true
+            }
+        }
+    }
+
+    /// Add the adjacency list entries of a new edge from `a` to `b`,
+    /// inserting the nodes if necessary.
+    fn add_links(nodes: &mut IndexMap<N, Vec<(N, CompactDirection)>, S>, a: N, b: N) {
+        nodes
+            .entry(a)
+            .or_insert_with(|| Vec::with_capacity(1))
+            .push((b, CompactDirection::Outgoing));
+        if a != b {
+            // self loops don't have the Incoming entry
+            nodes
+                .entry(b)
+                .or_insert_with(|| Vec::with_capacity(1))
+                .push((a, CompactDirection::Incoming));
+        }
+    }
+
+    /// Get the entry of the edge connecting `a` with `b`, for in-place
+    /// manipulation of its weight.
+    ///
+    /// Inserting a weight into a vacant entry adds the edge, and the nodes
+    /// `a` and `b` if they do not exist, like [`add_edge`](#method.add_edge).
+    ///
+    /// ```
+    /// use petgraph::graphmap::UnGraphMap;
+    ///
+    /// // count how often two words appear next to each other
+    /// let words = ["a", "rose", "is", "a", "rose", "is", "a", "rose"];
+    /// let mut g = UnGraphMap::new();
+    /// for pair in words.windows(2) {
+    ///     g.edge_entry(pair[0], pair[1])
+    ///         .and_modify(|count| *count += 1)
+    ///         .or_insert(1);
+    /// }
+    /// assert_eq!(g.edge_weight("a", "rose"), Some(&3));
+    /// assert_eq!(g.edge_weight("rose", "is"), Some(&2));
+    /// assert_eq!(g.edge_weight("is", "a"), Some(&2));
+    /// ```
+    pub fn edge_entry(&mut self, a: N, b: N) -> EdgeEntry<N, E, Ty, S> {
+        EdgeEntry {
+            a,
+            b,
+            entry: self.edges.entry(Self::edge_key(a, b)),
+            nodes: &mut self.nodes,
+            ty: self.ty,
+        }
+    }
+
     /// Remove edge relation from a to b
     ///
     /// Return `true` if it did exist.
@@ -377,11 +1132,313 @@ where
         weight
     }
 
+    /// Remove all edges of `a` in the specified direction, and return the
+    /// number of edges removed. Node `a` itself stays in the graph.
+    ///
+    /// - `Directed`, `Outgoing`: All edges from `a`.
+    /// - `Directed`, `Incoming`: All edges to `a`.
+    /// - `Undirected`: All edges connected to `a`.
+    ///
+    /// A self loop counts as both outgoing and incoming.
+    ///
+    /// Computes in **O(e * k)** time, where **e** is the number of removed
+    /// edges and **k** the largest degree of `a` and its neighbors.
+    ///
+    /// ```
+    /// use petgraph::graphmap::DiGraphMap;
+    /// use petgraph::Direction::Outgoing;
+    ///
+    /// let mut g = DiGraphMap::<_, ()>::from_edges(&[(1, 2), (1, 3), (3, 1)]);
+    /// assert_eq!(g.remove_edges_directed(1, Outgoing), 2);
+    /// assert_eq!(g.all_edges().map(|(a, b, _)| (a, b)).collect::<Vec<_>>(), vec![(3, 1)]);
+    /// ```
+    pub fn remove_edges_directed(&mut self, a: N, dir: Direction) -> usize {
+        let neighbors = self.neighbors_directed(a, dir).collect::<Vec<_>>();
+        for &n in &neighbors {
+            let weight = match dir {
+                Outgoing => self.remove_edge(a, n),
+                Incoming => self.remove_edge(n, a),
+            };
+            debug_assert!(weight.is_some());
+        }
+        neighbors.len()
+    }
+
+    /// Remove all edges connected to `n`, and return the number of edges
+    /// removed.
+    ///
+    /// Unlike [`remove_node`](#method.remove_node), node `n` stays in the
+    /// graph, at the same position in the node order.
+    ///
+    /// ```
+    /// use petgraph::graphmap::DiGraphMap;
+    ///
+    /// let mut g = DiGraphMap::<_, ()>::from_edges(&[(1, 2), (2, 3), (3, 1)]);
+    /// assert_eq!(g.disconnect_node(2), 2);
+    /// assert_eq!(g.nodes().collect::<Vec<_>>(), vec![1, 2, 3]);
+    /// assert_eq!(g.edge_count(), 1);
+    /// ```
+    pub fn disconnect_node(&mut self, n: N) -> usize {
+        self.remove_edges_directed(n, Outgoing) + self.remove_edges_directed(n, Incoming)
+    }
+
+    /// Rename node `old` to `new`, keeping its position in the node order
+    /// and all its edges.
+    ///
+    /// Return an error if `old` is not in the graph, or if `new` already is,
+    /// leaving the graph unchanged. Renaming a node to itself does nothing.
+    ///
+    /// Computes in **O(e * k)** time, where **e** is the number of edges of
+    /// `old` and **k** the largest degree of its neighbors.
+    ///
+    /// ```
+    /// use petgraph::graphmap::{DiGraphMap, RelabelError};
+    ///
+    /// let mut g = DiGraphMap::<_, u32>::from_edges(&[("a", "b", 1), ("b", "c", 2)]);
+    /// g.relabel_node("b", "x").unwrap();
+    /// assert_eq!(g.nodes().collect::<Vec<_>>(), vec!["a", "x", "c"]);
+    /// assert_eq!(g.edge_weight("a", "x"), Some(&1));
+    /// assert_eq!(g.relabel_node("x", "c"), Err(RelabelError::NodeExists));
+    /// assert_eq!(g.relabel_node("b", "y"), Err(RelabelError::NodeNotFound));
+    /// ```
+    pub fn relabel_node(&mut self, old: N, new: N) -> Result<(), RelabelError> {
+        if !self.nodes.contains_key(&old) {
+            return Err(RelabelError::NodeNotFound);
+        }
+        if old == new {
+            return Ok(());
+        }
+        if self.nodes.contains_key(&new) {
+            return Err(RelabelError::NodeExists);
+        }
+        let rename = |n: N| if n == old { new } else { n };
+
+        // Replace the node, then swap it back into the position of `old`.
+        let (index, _, mut links) = self.nodes.swap_remove_full(&old).unwrap();
+        for link in &mut links {
+            let (neighbor, dir) = *link;
+            let edge = if dir == CompactDirection::Outgoing {
+                Self::edge_key(old, neighbor)
+            } else {
+                Self::edge_key(neighbor, old)
+            };
+            let (edge_index, _, weight) = self.edges.swap_remove_full(&edge).unwrap();
+            let renamed = Self::edge_key(rename(edge.0), rename(edge.1));
+            self.edges.insert(renamed, weight);
+            self.edges.swap_indices(edge_index, self.edges.len() - 1);
+
+            link.0 = rename(neighbor);
+            if neighbor != old {
+                let back = self.nodes.get_mut(&neighbor).unwrap();
+                for back_link in back.iter_mut().filter(|link| link.0 == old) {
+                    back_link.0 = new;
+                }
+            }
+        }
+        self.nodes.insert(new, links);
+        self.nodes.swap_indices(index, self.nodes.len() - 1);
+        Ok(())
+    }
+
+    /// Keep all edges for which `f` returns `true`, remove the others.
+    ///
+    /// `f` is called once per edge with its endpoints and a mutable reference
+    /// to its weight, in the order of [`all_edges`](#method.all_edges). The
+    /// order of the remaining edges is preserved.
+    ///
+    /// Computes in **O(|V| + |E|)** time (average).
+    ///
+    /// ```
+    /// use petgraph::graphmap::DiGraphMap;
+    ///
+    /// let mut g = DiGraphMap::<_, i32>::from_edges(&[(1, 2, -1), (2, 3, 1), (3, 1, -2)]);
+    /// g.retain_edges(|_, _, w| *w >= 0);
+    /// assert_eq!(g.all_edges().collect::<Vec<_>>(), vec![(2, 3, &1)]);
+    /// ```
+    pub fn retain_edges<F>(&mut self, mut f: F)
+    where
+        F: FnMut(N, N, &mut E) -> bool,
+    {
+        self.edges.retain(|&(a, b), weight| f(a, b, weight));
+        let edges = &self.edges;
+        for (&n, links) in self.nodes.iter_mut() {
+            links.retain(|&(m, dir)| {
+                let edge = if dir == CompactDirection::Outgoing {
+                    Self::edge_key(n, m)
+                } else {
+                    Self::edge_key(m, n)
+                };
+                edges.contains_key(&edge)
+            });
+        }
+    }
+
+    /// Replace the edge from `a` to `b` by a path `a → m → b` through node `m`.
+    ///
+    /// The weight of the removed edge is split into the weights of the two new
+    /// edges by `split`, which returns `(weight of a → m, weight of m → b)`.
+    /// Node `m` is inserted if it isn't already part of the graph.
+    ///
+    /// Return `true` if the edge was subdivided. If there is no edge from `a`
+    /// to `b`, if `m` is `a` or `b`, or if an edge `a → m` or `m → b` already
+    /// exists, return `false` and leave the graph unchanged.
+    ///
+    /// ```
+    /// use petgraph::graphmap::DiGraphMap;
+    ///
+    /// let mut g = DiGraphMap::new();
+    /// g.add_edge("a", "b", 10);
+    /// assert!(!g.subdivide_edge("a", "b", "b", |w| (w, w)));
+    /// assert!(g.subdivide_edge("a", "b", "m", |w| (w / 2, w - w / 2)));
+    /// assert!(!g.contains_edge("a", "b"));
+    /// assert_eq!(g.edge_weight("a", "m"), Some(&5));
+    /// assert_eq!(g.edge_weight("m", "b"), Some(&5));
+    /// ```
+    pub fn subdivide_edge<F>(&mut self, a: N, b: N, m: N, split: F) -> bool
+    where
+        F: FnOnce(E) -> (E, E),
+    {
+        if m == a || m == b || self.contains_edge(a, m) || self.contains_edge(m, b) {
+            return false;
+        }
+        match self.remove_edge(a, b) {
+            None => false,
+            Some(weight) => {
+                let (wa, wb) = split(weight);
+                self.add_node(m);
+                self.add_edge(a, m, wa);
+                self.add_edge(m, b, wb);
+                true
+            }
+        }
+    }
+
+    /// Reverse the direction of all edges in place.
+    ///
+    /// This is a no-op for undirected graphs. To traverse the edges in
+    /// reverse without modifying the graph, use the
+    /// [`Reversed`](../visit/struct.Reversed.html) adaptor on `&GraphMap`
+    /// instead.
+    ///
+    /// Computes in **O(|V| + |E|)** time (average).
+    ///
+    /// ```
+    /// use petgraph::graphmap::DiGraphMap;
+    ///
+    /// let mut g = DiGraphMap::<_, ()>::from_edges(&[(1, 2), (2, 3)]);
+    /// g.reverse();
+    /// assert!(g.contains_edge(2, 1));
+    /// assert!(g.contains_edge(3, 2));
+    /// assert!(!g.contains_edge(1, 2));
+    /// ```
+    pub fn reverse(&mut self) {
+        if !Ty::is_directed() {
+            return;
+        }
+        let edges: Vec<_> = self.edges.drain(..).collect();
+        self.edges
+            .extend(edges.into_iter().map(|((a, b), weight)| ((b, a), weight)));
+        for (&n, links) in self.nodes.iter_mut() {
+            for link in links.iter_mut() {
+                // self loops only have the Outgoing entry
+                if link.0 != n {
+                    link.1 = link.1.opposite();
+                }
+            }
+        }
+    }
+
+    /// Contract node `remove` into node `keep`.
+    ///
+    /// Every edge incident to `remove` is rerouted to `keep`, preserving its
+    /// direction, and `remove` is removed from the graph. When a rerouted edge
+    /// already exists, `combine` is called with the existing weight and the
+    /// weight of the rerouted edge to update it. Edges between `keep` and
+    /// `remove` become self loops at `keep`, which are dropped unless
+    /// `keep_self_loops` is `true`.
+    ///
+    /// Return `false`, leaving the graph unchanged, if either node doesn't
+    /// exist or if `keep == remove`.
+    ///
+    /// ```
+    /// use petgraph::graphmap::UnGraphMap;
+    ///
+    /// let mut g = UnGraphMap::<_, u32>::from_edges(&[(1, 2, 1), (2, 3, 2), (1, 3, 4)]);
+    /// assert!(g.contract_nodes(1, 2, false, |w, other| *w += other));
+    /// assert_eq!(g.node_count(), 2);
+    /// assert_eq!(g.edge_weight(1, 3), Some(&6));
+    /// assert!(!g.contains_edge(1, 1));
+    /// ```
+    pub fn contract_nodes<F>(
+        &mut self,
+        keep: N,
+        remove: N,
+        keep_self_loops: bool,
+        mut combine: F,
+    ) -> bool
+    where
+        F: FnMut(&mut E, E),
+    {
+        if keep == remove || !self.contains_node(keep) {
+            return false;
+        }
+        let links = match self.nodes.get(&remove) {
+            None => return false,
+            Some(links) => links.clone(),
+        };
+        let reroute = |n: N| if n == remove { keep } else { n };
+        for (m, dir) in links {
+            let (a, b) = if dir == CompactDirection::Outgoing {
+                (remove, m)
+            } else {
+                (m, remove)
+            };
+            let weight = match self.remove_edge(a, b) {
+                Some(weight) => weight,
+                None => continue,
+            };
+            let (a, b) = (reroute(a), reroute(b));
+            if a == b && !keep_self_loops {
+                continue;
+            }
+            match self.edge_weight_mut(a, b) {
+                Some(existing) => combine(existing, weight),
+                None => {
+                    self.add_edge(a, b, weight);
+                }
+            }
+        }
+        self.remove_node(remove);
+        true
+    }
+
     /// Return `true` if the edge connecting `a` with `b` is contained in the graph.
     pub fn contains_edge(&self, a: N, b: N) -> bool {
         self.edges.contains_key(&Self::edge_key(a, b))
     }
 
+    /// Return the edge id of the edge connecting `a` with `b`, if it exists.
+    ///
+    /// The edge id is the pair of endpoints the edge is stored under, which
+    /// for an undirected graph is the same no matter the order of `a` and `b`.
+    /// It can be passed to [`EdgeIndexable::to_index`](../visit/trait.EdgeIndexable.html#tymethod.to_index).
+    ///
+    /// ```
+    /// use petgraph::graphmap::UnGraphMap;
+    /// use petgraph::visit::EdgeIndexable;
+    ///
+    /// let g = UnGraphMap::<_, ()>::from_edges(&[(1, 2), (3, 2)]);
+    /// let id = g.find_edge(3, 2).unwrap();
+    /// assert_eq!(id, (2, 3));
+    /// assert_eq!(EdgeIndexable::to_index(&g, id), 1);
+    /// assert_eq!(g.find_edge(1, 3), None);
+    /// ```
+    pub fn find_edge(&self, a: N, b: N) -> Option<(N, N)> {
+        self.edges
+            .get_key_value(&Self::edge_key(a, b))
+            .map(|(&key, _)| key)
+    }
+
     /// Return an iterator over the nodes of the graph.
     ///
     /// Iterator element type is `N`.
@@ -440,6 +1497,60 @@ where
         }
     }
 
+    /// Return an iterator over the nodes reachable from `start`, in
+    /// breadth-first order, starting with `start` itself.
+    ///
+    /// - `Directed`: Follows outgoing edges.
+    /// - `Undirected`: Follows all edges.
+    ///
+    /// This is a shorthand for driving a [`Bfs`](../visit/struct.Bfs.html)
+    /// over the graph. Produces an empty iterator if `start` doesn't exist.<br>
+    /// Iterator element type is `N`.
+    ///
+    /// ```
+    /// use petgraph::graphmap::DiGraphMap;
+    ///
+    /// let g = DiGraphMap::<_, ()>::from_edges(&[(1, 2), (1, 3), (2, 4), (4, 1)]);
+    /// assert_eq!(g.bfs(1).collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+    /// ```
+    pub fn bfs(&self, start: N) -> BfsNodes<N, E, Ty, S>
+    where
+        S: Default,
+    {
+        let mut bfs = visit::Bfs::new(self, start);
+        if !self.contains_node(start) {
+            bfs.stack.clear();
+        }
+        BfsNodes { graph: self, bfs }
+    }
+
+    /// Return an iterator over the nodes reachable from `start`, in
+    /// depth-first preorder, starting with `start` itself.
+    ///
+    /// - `Directed`: Follows outgoing edges.
+    /// - `Undirected`: Follows all edges.
+    ///
+    /// This is a shorthand for driving a [`Dfs`](../visit/struct.Dfs.html)
+    /// over the graph. Produces an empty iterator if `start` doesn't exist.<br>
+    /// Iterator element type is `N`.
+    ///
+    /// ```
+    /// use petgraph::graphmap::DiGraphMap;
+    ///
+    /// let g = DiGraphMap::<_, ()>::from_edges(&[(1, 2), (1, 3), (2, 4), (4, 1)]);
+    /// assert_eq!(g.dfs(1).collect::<Vec<_>>(), vec![1, 3, 2, 4]);
+    /// ```
+    pub fn dfs(&self, start: N) -> DfsNodes<N, E, Ty, S>
+    where
+        S: Default,
+    {
+        let mut dfs = visit::Dfs::new(self, start);
+        if !self.contains_node(start) {
+            dfs.stack.clear();
+        }
+        DfsNodes { graph: self, dfs }
+    }
+
     /// Return an iterator of target nodes with an edge starting from `a`,
     /// paired with their respective edge weights.
     ///
@@ -448,7 +1559,7 @@ where
     ///
     /// Produces an empty iterator if the node doesn't exist.<br>
     /// Iterator element type is `(N, N, &E)`.
-    pub fn edges(&self, a: N) -> Edges<N, E, Ty> {
+    pub fn edges(&self, a: N) -> Edges<N, E, Ty, S> {
         Edges {
             from: a,
             iter: self.neighbors(a),
@@ -468,7 +1579,7 @@ where
     ///
     /// Produces an empty iterator if the node doesn't exist.<br>
     /// Iterator element type is `(N, N, &E)`.
-    pub fn edges_directed(&self, a: N, dir: Direction) -> EdgesDirected<N, E, Ty> {
+    pub fn edges_directed(&self, a: N, dir: Direction) -> EdgesDirected<N, E, Ty, S> {
         EdgesDirected {
             from: a,
             iter: self.neighbors_directed(a, dir),
@@ -477,37 +1588,1213 @@ where
         }
     }
 
-    /// Return a reference to the edge weight connecting `a` with `b`, or
-    /// `None` if the edge does not exist in the graph.
-    pub fn edge_weight(&self, a: N, b: N) -> Option<&E> {
-        self.edges.get(&Self::edge_key(a, b))
-    }
-
-    /// Return a mutable reference to the edge weight connecting `a` with `b`, or
-    /// `None` if the edge does not exist in the graph.
-    pub fn edge_weight_mut(&mut self, a: N, b: N) -> Option<&mut E> {
-        self.edges.get_mut(&Self::edge_key(a, b))
+    /// Return an iterator over the same edges as
+    /// [`edges_directed`](#method.edges_directed), with a mutable reference
+    /// to their weight.
+    ///
+    /// The edges are produced in the order of [`all_edges`](#method.all_edges)
+    /// rather than in adjacency order, since they are reached by walking the
+    /// mutable iterator over all edges. Iterating to the end therefore takes
+    /// **O(|E|)** time, not time proportional to the degree of `a`; avoid
+    /// calling this for every node of a large graph, and use
+    /// [`all_edges_mut`](#method.all_edges_mut) instead.
+    ///
+    /// Produces an empty iterator if the node doesn't exist.<br>
+    /// Iterator element type is `(N, N, &mut E)`.
+    ///
+    /// ```
+    /// use petgraph::graphmap::DiGraphMap;
+    /// use petgraph::Direction::Outgoing;
+    ///
+    /// let mut g = DiGraphMap::<_, i32>::from_edges(&[(1, 2, 1), (1, 3, 2), (3, 1, 3)]);
+    /// for (_, _, weight) in g.edges_directed_mut(1, Outgoing) {
+    ///     *weight = -*weight;
+    /// }
+    /// assert_eq!(g.edge_weight(1, 3), Some(&-2));
+    /// assert_eq!(g.edge_weight(3, 1), Some(&3));
+    /// ```
+    pub fn edges_directed_mut(&mut self, a: N, dir: Direction) -> EdgesDirectedMut<N, E, Ty> {
+        let mut targets: Vec<_> = self
+            .edges_directed(a, dir)
+            .map(|(x, y, _)| {
+                let index = self.edges.get_index_of(&Self::edge_key(x, y)).unwrap();
+                (index, x, y)
+            })
+            .collect();
+        targets.sort_unstable_by_key(|&(index, _, _)| index);
+        EdgesDirectedMut {
+            targets: targets.into_iter(),
+            position: 0,
+            inner: self.edges.iter_mut(),
+            ty: self.ty,
+        }
+    }
+
+    /// Return a reference to the edge weight connecting `a` with `b`, or
+    /// `None` if the edge does not exist in the graph.
+    pub fn edge_weight(&self, a: N, b: N) -> Option<&E> {
+        self.edges.get(&Self::edge_key(a, b))
+    }
+
+    /// Return a mutable reference to the edge weight connecting `a` with `b`, or
+    /// `None` if the edge does not exist in the graph.
+    pub fn edge_weight_mut(&mut self, a: N, b: N) -> Option<&mut E> {
+        self.edges.get_mut(&Self::edge_key(a, b))
+    }
+
+    /// Return a mutable reference to the edge weight connecting `a` with `b`,
+    /// first adding the edge with the weight `default()` if it does not exist.
+    ///
+    /// Like [`add_edge`](#method.add_edge), this adds the nodes `a` and `b` if
+    /// they do not exist.
+    ///
+    /// ```
+    /// use petgraph::graphmap::DiGraphMap;
+    ///
+    /// let mut g = DiGraphMap::new();
+    /// for &(a, b) in &[(1, 2), (2, 3), (1, 2)] {
+    ///     *g.edge_weight_or_insert_with(a, b, || 0) += 1;
+    /// }
+    /// assert_eq!(g.edge_weight(1, 2), Some(&2));
+    /// assert_eq!(g.edge_weight(2, 3), Some(&1));
+    /// ```
+    pub fn edge_weight_or_insert_with<F>(&mut self, a: N, b: N, default: F) -> &mut E
+    where
+        F: FnOnce() -> E,
+    {
+        self.edge_entry(a, b).or_insert_with(default)
+    }
+
+    /// Return an iterator over all edges of the graph with their weight in arbitrary order.
+    ///
+    /// Iterator element type is `(N, N, &E)`
+    pub fn all_edges(&self) -> AllEdges<N, E, Ty> {
+        AllEdges {
+            inner: self.edges.iter(),
+            ty: self.ty,
+        }
+    }
+
+    /// Return a parallel iterator over all edges of the graph with their weight
+    /// in arbitrary order.
+    ///
+    /// Iterator element type is `(N, N, &E)`
+    ///
+    /// ```
+    /// use petgraph::graphmap::DiGraphMap;
+    /// use rayon::prelude::*;
+    ///
+    /// let g = DiGraphMap::<_, u32>::from_edges(&[(1, 2, 3), (2, 3, 4)]);
+    /// let total: u32 = g.par_all_edges().map(|(_, _, w)| *w).sum();
+    /// assert_eq!(total, 7);
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn par_all_edges(&self) -> ParAllEdges<'_, N, E>
+    where
+        N: Send + Sync,
+        E: Sync,
+    {
+        ParAllEdges {
+            iter: self.edges.par_iter(),
+        }
+    }
+
+    /// Return an iterator over all edges of the graph in arbitrary order, with a mutable reference
+    /// to their weight.
+    ///
+    /// Iterator element type is `(N, N, &mut E)`
+    pub fn all_edges_mut(&mut self) -> AllEdgesMut<N, E, Ty> {
+        AllEdgesMut {
+            inner: self.edges.iter_mut(),
+            ty: self.ty,
+        }
+    }
+
+    /// Return a copy of all edges of the graph with their weight, in the
+    /// order of [`all_edges`](#method.all_edges).
+    ///
+    /// The iterator returned by [`all_edges_mut`](#method.all_edges_mut)
+    /// can't be cloned; use this instead when a copy of the edges is needed
+    /// that can be cloned, printed, or kept while the graph is modified.
+    ///
+    /// ```
+    /// use petgraph::graphmap::DiGraphMap;
+    ///
+    /// let mut g = DiGraphMap::<_, u32>::from_edges(&[(1, 2, 3), (2, 3, 4)]);
+    /// let before = g.snapshot_edges();
+    /// for (_, _, w) in g.all_edges_mut() {
+    ///     *w *= 10;
+    /// }
+    /// assert_eq!(before, vec![(1, 2, 3), (2, 3, 4)]);
+    /// ```
+    pub fn snapshot_edges(&self) -> Vec<(N, N, E)>
+    where
+        E: Clone,
+    {
+        self.all_edges()
+            .map(|(a, b, weight)| (a, b, weight.clone()))
+            .collect()
+    }
+
+    /// Return an iterator over the nodes that have a self loop, paired with
+    /// the weight of the loop.
+    ///
+    /// Iterator element type is `(N, &E)`.
+    ///
+    /// ```
+    /// use petgraph::graphmap::DiGraphMap;
+    ///
+    /// let g = DiGraphMap::<_, _>::from_edges(&[(0, 0, 'a'), (0, 1, 'b'), (1, 1, 'c')]);
+    /// let loops: Vec<_> = g.self_loops().collect();
+    /// assert_eq!(loops, vec![(0, &'a'), (1, &'c')]);
+    /// ```
+    pub fn self_loops(&self) -> SelfLoops<N, E, S> {
+        SelfLoops {
+            nodes: self.nodes.keys(),
+            edges: &self.edges,
+        }
+    }
+
+    /// Return an iterator over the weights of all edges of the graph, in the
+    /// same order as [`all_edges`](#method.all_edges).
+    ///
+    /// Iterator element type is `&E`.
+    ///
+    /// ```
+    /// use petgraph::graphmap::UnGraphMap;
+    ///
+    /// let g = UnGraphMap::<_, u32>::from_edges(&[(1, 2, 3), (2, 3, 4)]);
+    /// assert_eq!(g.edge_weights().sum::<u32>(), 7);
+    /// ```
+    pub fn edge_weights(&self) -> EdgeWeights<N, E> {
+        EdgeWeights {
+            iter: self.edges.values(),
+        }
+    }
+
+    /// Return an iterator over mutable references to the weights of all edges
+    /// of the graph, in the same order as [`all_edges`](#method.all_edges).
+    ///
+    /// Iterator element type is `&mut E`.
+    pub fn edge_weights_mut(&mut self) -> EdgeWeightsMut<N, E> {
+        EdgeWeightsMut {
+            iter: self.edges.values_mut(),
+        }
+    }
+
+    /// Return the number of edges incident to `n`.
+    ///
+    /// For directed graphs, this is the sum of the in- and out-degree. A self
+    /// loop has both of its endpoints at `n` and counts twice.
+    ///
+    /// Returns `0` if the node doesn't exist.
+    ///
+    /// ```
+    /// use petgraph::graphmap::DiGraphMap;
+    ///
+    /// let g = DiGraphMap::<_, ()>::from_edges(&[(0, 1), (2, 0), (0, 0)]);
+    /// assert_eq!(g.degree(0), 4);
+    /// assert_eq!(g.out_degree(0), 2);
+    /// assert_eq!(g.in_degree(0), 2);
+    /// ```
+    pub fn degree(&self, n: N) -> usize {
+        match self.nodes.get(&n) {
+            None => 0,
+            Some(neighbors) => {
+                let (out, inc) = Self::directed_degrees(n, neighbors);
+                out + inc
+            }
+        }
+    }
+
+    /// Return the number of edges starting from `n`; a self loop counts once.
+    ///
+    /// For undirected graphs, this is equivalent to [`degree`](#method.degree).
+    pub fn out_degree(&self, n: N) -> usize {
+        if !Ty::is_directed() {
+            return self.degree(n);
+        }
+        self.nodes
+            .get(&n)
+            .map_or(0, |neighbors| Self::directed_degrees(n, neighbors).0)
+    }
+
+    /// Return the number of edges ending at `n`; a self loop counts once.
+    ///
+    /// For undirected graphs, this is equivalent to [`degree`](#method.degree).
+    pub fn in_degree(&self, n: N) -> usize {
+        if !Ty::is_directed() {
+            return self.degree(n);
+        }
+        self.nodes
+            .get(&n)
+            .map_or(0, |neighbors| Self::directed_degrees(n, neighbors).1)
+    }
+
+    /// Return the number of neighbors of `n`, i.e. the number of nodes
+    /// produced by [`neighbors`](#method.neighbors), without iterating them.
+    ///
+    /// Unlike [`degree`](#method.degree), a self loop makes `n` its own
+    /// neighbor once.
+    ///
+    /// Returns `0` if the node doesn't exist.
+    ///
+    /// ```
+    /// use petgraph::graphmap::UnGraphMap;
+    /// use petgraph::Direction::Incoming;
+    ///
+    /// let g = UnGraphMap::<_, ()>::from_edges(&[(0, 1), (2, 0), (0, 0)]);
+    /// assert_eq!(g.neighbor_count(0), 3);
+    /// assert_eq!(g.neighbor_count_directed(0, Incoming), 3);
+    /// ```
+    pub fn neighbor_count(&self, n: N) -> usize {
+        self.neighbor_count_directed(n, Outgoing)
+    }
+
+    /// Return the number of neighbors of `n` in direction `dir`, i.e. the
+    /// number of nodes produced by
+    /// [`neighbors_directed`](#method.neighbors_directed), without iterating
+    /// them.
+    ///
+    /// Returns `0` if the node doesn't exist.
+    pub fn neighbor_count_directed(&self, n: N, dir: Direction) -> usize {
+        match self.nodes.get(&n) {
+            None => 0,
+            Some(neighbors) if !Ty::is_directed() => neighbors.len(),
+            Some(neighbors) => {
+                let (out, inc) = Self::directed_degrees(n, neighbors);
+                match dir {
+                    Outgoing => out,
+                    Incoming => inc,
+                }
+            }
+        }
+    }
+
+    /// Return the sum of the weights of all edges incident to `n`, also known
+    /// as the *strength* of the node.
+    ///
+    /// For directed graphs, this is the sum of the weighted in- and
+    /// out-degree. As in [`degree`](#method.degree), a self loop has both of
+    /// its endpoints at `n` and its weight counts twice.
+    ///
+    /// Returns the empty sum if the node doesn't exist.
+    ///
+    /// ```
+    /// use petgraph::graphmap::DiGraphMap;
+    ///
+    /// let g = DiGraphMap::<_, u32>::from_edges(&[(0, 1, 2), (2, 0, 3), (0, 0, 5)]);
+    /// assert_eq!(g.weighted_degree(0), 15);
+    /// assert_eq!(g.weighted_out_degree(0), 7);
+    /// assert_eq!(g.weighted_in_degree(0), 8);
+    /// ```
+    pub fn weighted_degree(&self, n: N) -> E
+    where
+        E: Sum + Copy,
+    {
+        if Ty::is_directed() {
+            self.edges_directed(n, Outgoing)
+                .chain(self.edges_directed(n, Incoming))
+                .map(|(_, _, &w)| w)
+                .sum()
+        } else {
+            let self_loop = self.edge_weight(n, n);
+            self.edges(n)
+                .map(|(_, _, w)| w)
+                .chain(self_loop)
+                .cloned()
+                .sum()
+        }
+    }
+
+    /// Return the sum of the weights of all edges starting from `n`; a self
+    /// loop counts once.
+    ///
+    /// For undirected graphs, this is equivalent to
+    /// [`weighted_degree`](#method.weighted_degree).
+    pub fn weighted_out_degree(&self, n: N) -> E
+    where
+        E: Sum + Copy,
+    {
+        if !Ty::is_directed() {
+            return self.weighted_degree(n);
+        }
+        self.edges_directed(n, Outgoing).map(|(_, _, &w)| w).sum()
+    }
+
+    /// Return the sum of the weights of all edges ending at `n`; a self loop
+    /// counts once.
+    ///
+    /// For undirected graphs, this is equivalent to
+    /// [`weighted_degree`](#method.weighted_degree).
+    pub fn weighted_in_degree(&self, n: N) -> E
+    where
+        E: Sum + Copy,
+    {
+        if !Ty::is_directed() {
+            return self.weighted_degree(n);
+        }
+        self.edges_directed(n, Incoming).map(|(_, _, &w)| w).sum()
+    }
+
+    /// Return all nodes within `k` hops of `n`, in breadth-first order.
+    ///
+    /// Edges are followed in direction `dir`, as in
+    /// [`neighbors_directed`](#method.neighbors_directed); the direction is
+    /// ignored for undirected graphs. If `include_start` is `true`, `n` itself
+    /// is the first element of the result.
+    ///
+    /// Produces an empty vector if the node doesn't exist.
+    ///
+    /// ```
+    /// use petgraph::graphmap::UnGraphMap;
+    /// use petgraph::Outgoing;
+    ///
+    /// let g = UnGraphMap::<_, ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+    /// assert_eq!(g.k_hop_neighborhood(0, 2, Outgoing, false), vec![1, 2]);
+    /// assert_eq!(g.k_hop_neighborhood(0, 2, Outgoing, true), vec![0, 1, 2]);
+    /// ```
+    pub fn k_hop_neighborhood(
+        &self,
+        n: N,
+        k: usize,
+        dir: Direction,
+        include_start: bool,
+    ) -> Vec<N> {
+        let mut result = Vec::new();
+        if !self.contains_node(n) {
+            return result;
+        }
+        if include_start {
+            result.push(n);
+        }
+        let mut seen = HashSet::new();
+        seen.insert(n);
+        let mut queue = VecDeque::new();
+        queue.push_back((n, 0));
+        while let Some((node, depth)) = queue.pop_front() {
+            if depth == k {
+                continue;
+            }
+            for next in self.neighbors_directed(node, dir) {
+                if seen.insert(next) {
+                    result.push(next);
+                    queue.push_back((next, depth + 1));
+                }
+            }
+        }
+        result
+    }
+
+    /// Return `true` if the graph has an *Eulerian circuit*: a closed walk that
+    /// uses every edge exactly once.
+    ///
+    /// - `Undirected`: every node has even degree (a self loop adds two).
+    /// - `Directed`: every node has equal in- and out-degree.
+    ///
+    /// In both cases all nodes that have edges must be (weakly) connected;
+    /// isolated nodes are ignored. A graph without edges trivially has an
+    /// Eulerian circuit.
+    pub fn has_eulerian_circuit(&self) -> bool {
+        let balanced = self.nodes.iter().all(|(&n, neighbors)| {
+            let (out, inc) = Self::directed_degrees(n, neighbors);
+            if Ty::is_directed() {
+                out == inc
+            } else {
+                (out + inc) % 2 == 0
+            }
+        });
+        balanced && self.edge_bearing_nodes_connected()
+    }
+
+    /// Return `true` if the graph has an *Eulerian path*: a walk, not
+    /// necessarily closed, that uses every edge exactly once.
+    ///
+    /// - `Undirected`: zero or two nodes have odd degree (a self loop adds two).
+    /// - `Directed`: either every node has equal in- and out-degree, or exactly
+    ///   one node has one more outgoing than incoming edge, exactly one has one
+    ///   more incoming than outgoing edge, and all others are balanced.
+    ///
+    /// In both cases all nodes that have edges must be (weakly) connected;
+    /// isolated nodes are ignored. Every graph with an Eulerian circuit also
+    /// has an Eulerian path.
+    pub fn has_eulerian_path(&self) -> bool {
+        let (mut starts, mut ends) = (0, 0);
+        for (&n, neighbors) in &self.nodes {
+            let (out, inc) = Self::directed_degrees(n, neighbors);
+            if Ty::is_directed() {
+                if out == inc + 1 {
+                    starts += 1;
+                } else if inc == out + 1 {
+                    ends += 1;
+                } else if out != inc {
+                    return false;
+                }
+            } else if (out + inc) % 2 == 1 {
+                starts += 1;
+            }
+        }
+        let degrees_ok = if Ty::is_directed() {
+            starts == ends && starts <= 1
+        } else {
+            starts == 0 || starts == 2
+        };
+        degrees_ok && self.edge_bearing_nodes_connected()
+    }
+
+    /// Count the outgoing and incoming edge endpoints in the adjacency list of
+    /// `n`. A self loop, stored once, counts as both.
+    fn directed_degrees(n: N, neighbors: &[(N, CompactDirection)]) -> (usize, usize) {
+        let mut out = 0;
+        let mut inc = 0;
+        for &(m, dir) in neighbors {
+            if m == n {
+                out += 1;
+                inc += 1;
+            } else if dir == CompactDirection::Outgoing {
+                out += 1;
+            } else {
+                inc += 1;
+            }
+        }
+        (out, inc)
+    }
+
+    /// Return `true` if all nodes with at least one edge are connected,
+    /// ignoring edge direction.
+    fn edge_bearing_nodes_connected(&self) -> bool {
+        let start = match self
+            .nodes
+            .iter()
+            .find(|(_, neighbors)| !neighbors.is_empty())
+        {
+            None => return true,
+            Some((&start, _)) => start,
+        };
+        let mut seen = HashSet::new();
+        seen.insert(start);
+        let mut stack = vec![start];
+        while let Some(node) = stack.pop() {
+            for &(next, _) in &self.nodes[&node] {
+                if seen.insert(next) {
+                    stack.push(next);
+                }
+            }
+        }
+        self.nodes
+            .iter()
+            .all(|(n, neighbors)| neighbors.is_empty() || seen.contains(n))
+    }
+
+    /// Remove node `n` and add *shortcut* edges so that shortest path distances
+    /// between the remaining nodes are preserved, as in the node contraction
+    /// step of contraction hierarchies.
+    ///
+    /// The cost of an edge is given by `edge_cost`. For every pair of neighbors
+    /// `u`, `v` of `n` (for a directed graph, every incoming neighbor `u` and
+    /// outgoing neighbor `v`), a shortcut `u → v` is added for the path
+    /// `u → n → v`, unless a *witness* path that avoids `n` and costs no more
+    /// exists. The weight of the shortcut is `combine(w1, w2)`, where `w1` and
+    /// `w2` are the weights of the edges `u → n` and `n → v`; `edge_cost` of it
+    /// should be the sum of their costs. If an edge `u → v` already exists and
+    /// costs more, it is replaced by the shortcut.
+    ///
+    /// Return the shortcuts that were added or replaced, or an empty vector if
+    /// `n` is not part of the graph.
+    ///
+    /// Edge costs must be non-negative.
+    ///
+    /// ```
+    /// use petgraph::graphmap::UnGraphMap;
+    ///
+    /// // edge weights are (length, road name)
+    /// let mut g = UnGraphMap::<_, _>::from_edges(&[(0, 1, (2, "a")), (1, 2, (3, "b"))]);
+    /// let shortcuts = g.contract_node_with_shortcuts(1, |w| w.0, |w1, w2| (w1.0 + w2.0, "a+b"));
+    /// assert_eq!(shortcuts, vec![(0, 2, (5, "a+b"))]);
+    /// ```
+    pub fn contract_node_with_shortcuts<F, K, C>(
+        &mut self,
+        n: N,
+        mut edge_cost: F,
+        mut combine: C,
+    ) -> Vec<(N, N, E)>
+    where
+        F: FnMut(&E) -> K,
+        K: Measure + Copy,
+        C: FnMut(&E, &E) -> E,
+        E: Clone,
+        S: Default,
+    {
+        let mut shortcuts = Vec::new();
+        if !self.contains_node(n) {
+            return shortcuts;
+        }
+        let incoming: Vec<(N, &E)> = self
+            .edges_directed(n, Incoming)
+            .filter(|&(u, _, _)| u != n)
+            .map(|(u, _, w)| (u, w))
+            .collect();
+        let outgoing: Vec<(N, &E)> = self
+            .edges_directed(n, Outgoing)
+            .filter(|&(_, v, _)| v != n)
+            .map(|(_, v, w)| (v, w))
+            .collect();
+
+        let without_n = visit::NodeFiltered::from_fn(&*self, |x| x != n);
+        for &(u, uw) in &incoming {
+            for &(v, vw) in &outgoing {
+                if u == v || (!Ty::is_directed() && v < u) {
+                    continue;
+                }
+                let via = edge_cost(uw) + edge_cost(vw);
+                let witness =
+                    crate::algo::dijkstra(&without_n, u, Some(v), |(_, _, w)| edge_cost(w));
+                match witness.get(&v) {
+                    Some(d) if *d <= via => {}
+                    _ => shortcuts.push((u, v, combine(uw, vw))),
+                }
+            }
+        }
+
+        self.remove_node(n);
+        for (u, v, w) in &shortcuts {
+            self.add_edge(*u, *v, w.clone());
+        }
+        shortcuts
+    }
+
+    /// Return the node-edge incidence matrix of the graph.
+    ///
+    /// Rows are indexed by node index and columns by edge index, in the order
+    /// given by `NodeIndexable` and `EdgeIndexable`.
+    ///
+    /// - `Directed`: the entry is `-1` for the source (tail) of an edge and `1`
+    ///   for its target (head). A self loop has a `0` column.
+    /// - `Undirected`: the entry is `1` for both endpoints of an edge. A self
+    ///   loop has a `2` at its node.
+    ///
+    /// ```
+    /// use petgraph::graphmap::DiGraphMap;
+    ///
+    /// let g = DiGraphMap::<_, ()>::from_edges(&[("a", "b"), ("b", "c")]);
+    /// assert_eq!(
+    ///     g.incidence_matrix(),
+    ///     vec![vec![-1, 0], vec![1, -1], vec![0, 1]]
+    /// );
+    /// ```
+    pub fn incidence_matrix(&self) -> Vec<Vec<i8>> {
+        let mut matrix = vec![vec![0; self.edge_count()]; self.node_count()];
+        for (e, &(a, b)) in self.edges.keys().enumerate() {
+            let ai = self.nodes.get_index_of(&a).unwrap();
+            let bi = self.nodes.get_index_of(&b).unwrap();
+            if Ty::is_directed() {
+                matrix[ai][e] -= 1;
+                matrix[bi][e] += 1;
+            } else {
+                matrix[ai][e] += 1;
+                matrix[bi][e] += 1;
+            }
+        }
+        matrix
+    }
+
+    /// Return the weighted Laplacian matrix **L = D - A** of the graph.
+    ///
+    /// Rows and columns are indexed by node index. The off-diagonal entry for
+    /// two adjacent nodes is the negated edge weight, and the diagonal holds
+    /// the weighted degree of each node, so every row sums to zero.
+    ///
+    /// Directed graphs use the symmetrized adjacency **A + Aᵀ**, i.e. edges are
+    /// treated as undirected and the weights of two opposite edges are summed.
+    /// Self loops cancel out in **D - A** and are ignored.
+    pub fn laplacian_matrix(&self) -> Vec<Vec<f64>>
+    where
+        E: Clone + Into<f64>,
+    {
+        let n = self.node_count();
+        let mut matrix = vec![vec![0.; n]; n];
+        for (&(a, b), weight) in &self.edges {
+            if a == b {
+                continue;
+            }
+            let ai = self.nodes.get_index_of(&a).unwrap();
+            let bi = self.nodes.get_index_of(&b).unwrap();
+            let w: f64 = weight.clone().into();
+            matrix[ai][bi] -= w;
+            matrix[bi][ai] -= w;
+            matrix[ai][ai] += w;
+            matrix[bi][bi] += w;
+        }
+        matrix
+    }
+
+    /// Return the edges crossing between two disjoint node sets, and their
+    /// total weight.
+    ///
+    /// An edge is part of the cut if one endpoint is in `side_a` and the other
+    /// is in `side_b`; for directed graphs, edges in both directions are
+    /// included. Edges internal to a set, or touching nodes in neither set,
+    /// are excluded. Edges are returned in the order of
+    /// [`all_edges`](#method.all_edges).
+    ///
+    /// ```
+    /// use petgraph::graphmap::UnGraphMap;
+    /// use std::collections::HashSet;
+    ///
+    /// let g = UnGraphMap::<_, i32>::from_edges(&[(0, 1, 1), (1, 2, 5), (2, 3, 1), (3, 0, 2)]);
+    /// let a: HashSet<_> = [0, 1].iter().cloned().collect();
+    /// let b: HashSet<_> = [2, 3].iter().cloned().collect();
+    /// let (edges, weight) = g.cut_edges(&a, &b);
+    /// assert_eq!(edges, vec![(1, 2, &5), (0, 3, &2)]);
+    /// assert_eq!(weight, 7);
+    /// ```
+    pub fn cut_edges<'a>(
+        &'a self,
+        side_a: &HashSet<N>,
+        side_b: &HashSet<N>,
+    ) -> (Vec<(N, N, &'a E)>, E)
+    where
+        E: Sum<&'a E>,
+    {
+        let edges: Vec<_> = self
+            .all_edges()
+            .filter(|&(a, b, _)| {
+                (side_a.contains(&a) && side_b.contains(&b))
+                    || (side_b.contains(&a) && side_a.contains(&b))
+            })
+            .collect();
+        let weight = edges.iter().map(|&(_, _, w)| w).sum();
+        (edges, weight)
+    }
+
+    /// Return a copy of the graph with its nodes relabeled to `0..n`, and
+    /// the mapping from new labels back to the original nodes.
+    ///
+    /// Labels are assigned in sorted node order, so the result does not depend
+    /// on the insertion order of the nodes.
+    ///
+    /// Computes in **O(|V| log |V| + |E|)** time (average).
+    ///
+    /// ```
+    /// use petgraph::graphmap::DiGraphMap;
+    ///
+    /// let g = DiGraphMap::<_, ()>::from_edges(&[("c", "a"), ("a", "b")]);
+    /// let (relabeled, nodes) = g.relabel_consecutive();
+    /// assert_eq!(nodes, vec!["a", "b", "c"]);
+    /// assert!(relabeled.contains_edge(2, 0));
+    /// assert!(relabeled.contains_edge(0, 1));
+    /// ```
+    pub fn relabel_consecutive(&self) -> (GraphMap<u32, E, Ty, S>, Vec<N>)
+    where
+        E: Clone,
+        S: Default,
+    {
+        let mut nodes: Vec<N> = self.nodes().collect();
+        nodes.sort();
+        let mut graph = GraphMap::with_capacity_default(self.node_count(), self.edge_count());
+        for i in 0..nodes.len() {
+            graph.add_node(i as u32);
+        }
+        for (a, b, w) in self.all_edges() {
+            let ai = nodes.binary_search(&a).unwrap() as u32;
+            let bi = nodes.binary_search(&b).unwrap() as u32;
+            graph.add_edge(ai, bi, w.clone());
+        }
+        (graph, nodes)
+    }
+
+    /// Return a copy of the graph in which all nodes with the same `key` are
+    /// merged into a single node.
+    ///
+    /// Every node `n` becomes the node `key(n)`, and every edge `(a, b)`
+    /// becomes the edge `(key(a), key(b))`. When several edges end up between
+    /// the same pair of nodes, their weights are merged with
+    /// `combine(existing, new)`. Edges between two nodes of the same group
+    /// become self loops.
+    ///
+    /// Computes in **O(|V| + |E|)** time (average).
+    ///
+    /// ```
+    /// use petgraph::graphmap::UnGraphMap;
+    ///
+    /// let g = UnGraphMap::<_, u32>::from_edges(&[('a', 'B', 1), ('A', 'c', 2), ('b', 'c', 4)]);
+    /// let merged = g.merge_nodes_by(|n| n.to_ascii_lowercase(), |x, y| x + y);
+    /// assert_eq!(merged.node_count(), 3);
+    /// assert_eq!(merged.edge_weight('a', 'b'), Some(&1));
+    /// assert_eq!(merged.edge_weight('a', 'c'), Some(&2));
+    /// assert_eq!(merged.edge_weight('b', 'c'), Some(&4));
+    /// ```
+    pub fn merge_nodes_by<K, F, G>(&self, mut key: F, mut combine: G) -> GraphMap<K, E, Ty, S>
+    where
+        K: NodeTrait,
+        F: FnMut(N) -> K,
+        G: FnMut(E, E) -> E,
+        E: Clone,
+        S: Default,
+    {
+        let mut keys = HashMap::with_capacity(self.node_count());
+        let mut graph = GraphMap::with_capacity_default(0, 0);
+        for n in self.nodes() {
+            let k = key(n);
+            keys.insert(n, k);
+            graph.add_node(k);
+        }
+        for (a, b, w) in self.all_edges() {
+            let (ka, kb) = (keys[&a], keys[&b]);
+            let weight = match graph.remove_edge(ka, kb) {
+                Some(old) => combine(old, w.clone()),
+                None => w.clone(),
+            };
+            graph.add_edge(ka, kb, weight);
+        }
+        graph
+    }
+
+    /// Return the sum of `weight` over all triangles of the graph.
+    ///
+    /// `weight` is called once per triangle with the weights of its three
+    /// edges, e.g. to compute the geometric mean used in weighted clustering
+    /// coefficients. With a constant weight of `1.`, this counts the
+    /// triangles.
+    ///
+    /// Edges are treated as undirected and self loops are ignored. In a
+    /// directed graph where two nodes are connected in both directions, the
+    /// weight of the edge from the smaller to the larger node is used.
+    ///
+    /// ```
+    /// use petgraph::graphmap::UnGraphMap;
+    ///
+    /// let g = UnGraphMap::<_, f64>::from_edges(&[(0, 1, 1.), (1, 2, 2.), (2, 0, 4.), (2, 3, 1.)]);
+    /// let geometric_mean = |a: &f64, b: &f64, c: &f64| (a * b * c).cbrt();
+    /// assert_eq!(g.weighted_triangle_count(geometric_mean), 2.);
+    /// assert_eq!(g.weighted_triangle_count(|_, _, _| 1.), 1.);
+    /// ```
+    pub fn weighted_triangle_count<F>(&self, mut weight: F) -> f64
+    where
+        F: FnMut(&E, &E, &E) -> f64,
+    {
+        // For every node, its neighbors that compare greater, in sorted order,
+        // so that every triangle a < b < c is found exactly once.
+        let mut higher: HashMap<N, Vec<N>> = HashMap::with_capacity(self.node_count());
+        for &(a, b) in self.edges.keys() {
+            let (a, b) = if a < b { (a, b) } else { (b, a) };
+            if a != b {
+                higher.entry(a).or_default().push(b);
+            }
+        }
+        for neighbors in higher.values_mut() {
+            neighbors.sort();
+            neighbors.dedup();
+        }
+
+        let edge = |a, b| {
+            self.edge_weight(a, b)
+                .or_else(|| self.edge_weight(b, a))
+                .unwrap()
+        };
+        let mut total = 0.;
+        for (&a, a_higher) in &higher {
+            for &b in a_higher {
+                let b_higher = match higher.get(&b) {
+                    Some(b_higher) => b_higher,
+                    None => continue,
+                };
+                for &c in b_higher {
+                    if a_higher.binary_search(&c).is_ok() {
+                        total += weight(edge(a, b), edge(b, c), edge(a, c));
+                    }
+                }
+            }
+        }
+        total
+    }
+
+    /// Split the graph into its connected components, each as its own
+    /// `GraphMap`.
+    ///
+    /// For directed graphs, the weakly connected components are returned.
+    /// Isolated nodes form components with a single node. Components are
+    /// ordered by their first node, and nodes and edges keep their relative
+    /// order from this graph.
+    ///
+    /// Computes in **O(|V| + |E|)** time (average).
+    ///
+    /// ```
+    /// use petgraph::graphmap::UnGraphMap;
+    ///
+    /// let mut g = UnGraphMap::<_, ()>::from_edges(&[(0, 1), (2, 3), (1, 4)]);
+    /// g.add_node(5);
+    /// let components = g.split_into_components();
+    /// assert_eq!(components.len(), 3);
+    /// assert_eq!(components[0].nodes().collect::<Vec<_>>(), vec![0, 1, 4]);
+    /// assert_eq!(components[1].nodes().collect::<Vec<_>>(), vec![2, 3]);
+    /// assert_eq!(components[2].nodes().collect::<Vec<_>>(), vec![5]);
+    /// ```
+    pub fn split_into_components(&self) -> Vec<GraphMap<N, E, Ty, S>>
+    where
+        E: Clone,
+        S: Default,
+    {
+        let mut components = UnionFind::new(self.node_count());
+        for &(a, b) in self.edges.keys() {
+            components.union(
+                self.nodes.get_index_of(&a).unwrap(),
+                self.nodes.get_index_of(&b).unwrap(),
+            );
+        }
+
+        // Map each representative to the position of its component.
+        let mut position = vec![std::usize::MAX; self.node_count()];
+        let mut graphs: Vec<GraphMap<N, E, Ty, S>> = Vec::new();
+        for (i, &n) in self.nodes.keys().enumerate() {
+            let root = components.find_mut(i);
+            if position[root] == std::usize::MAX {
+                position[root] = graphs.len();
+                graphs.push(GraphMap::with_capacity_default(0, 0));
+            }
+            graphs[position[root]].add_node(n);
+        }
+        for (&(a, b), weight) in &self.edges {
+            let root = components.find_mut(self.nodes.get_index_of(&a).unwrap());
+            graphs[position[root]].add_edge(a, b, weight.clone());
+        }
+        graphs
+    }
+
+    /// Create a new `GraphMap` by mapping the nodes and edge weights.
+    ///
+    /// Every node `n` becomes `node_map(n)`, and every edge `(a, b)` with
+    /// weight `w` becomes an edge between the mapped endpoints with weight
+    /// `edge_map(a, b, w)`. Nodes and edges keep their order.
+    ///
+    /// Since nodes are their own keys, nodes that are mapped to the same value
+    /// are merged into one node. Edges that end up between the same pair of
+    /// nodes are merged too, keeping the weight of the last one.
+    ///
+    /// ```
+    /// use petgraph::graphmap::DiGraphMap;
+    ///
+    /// let g = DiGraphMap::<_, u32>::from_edges(&[(1, 2, 10), (2, 3, 20)]);
+    /// let h = g.map(|n| n * 2, |_, _, w| w.to_string());
+    /// assert_eq!(h.nodes().collect::<Vec<_>>(), vec![2, 4, 6]);
+    /// assert_eq!(h.edge_weight(4, 6), Some(&"20".to_string()));
+    /// ```
+    pub fn map<F, G, N2, E2>(self, mut node_map: F, mut edge_map: G) -> GraphMap<N2, E2, Ty, S>
+    where
+        F: FnMut(N) -> N2,
+        G: FnMut(N, N, E) -> E2,
+        N2: NodeTrait,
+        S: Default,
+    {
+        let mut graph = GraphMap::with_capacity_default(self.node_count(), self.edge_count());
+        let mut mapped = HashMap::with_capacity(self.node_count());
+        for &n in self.nodes.keys() {
+            let m = graph.add_node(node_map(n));
+            mapped.insert(n, m);
+        }
+        for ((a, b), weight) in self.edges {
+            graph.add_edge(mapped[&a], mapped[&b], edge_map(a, b, weight));
+        }
+        graph
+    }
+
+    /// Create a new `GraphMap` by mapping the nodes and edge weights, and
+    /// dropping those for which a closure returns `None`.
+    ///
+    /// Nodes for which `node_map` returns `None` are dropped together with
+    /// their edges; `edge_map` is only called for edges between two kept
+    /// nodes, and drops the edge if it returns `None`. Nodes and edges keep
+    /// their order.
+    ///
+    /// As with [`map`](#method.map), nodes that are mapped to the same value
+    /// are merged into one node.
+    ///
+    /// ```
+    /// use petgraph::graphmap::DiGraphMap;
+    ///
+    /// let g = DiGraphMap::<_, i32>::from_edges(&[(1, 2, 1), (2, 3, -1), (3, 4, 1)]);
+    /// let h = g.filter_map(
+    ///     |n| if n != 4 { Some(n) } else { None },
+    ///     |_, _, w| if w > 0 { Some(w) } else { None },
+    /// );
+    /// assert_eq!(h.nodes().collect::<Vec<_>>(), vec![1, 2, 3]);
+    /// assert_eq!(h.all_edges().collect::<Vec<_>>(), vec![(1, 2, &1)]);
+    /// ```
+    pub fn filter_map<F, G, N2, E2>(
+        self,
+        mut node_map: F,
+        mut edge_map: G,
+    ) -> GraphMap<N2, E2, Ty, S>
+    where
+        F: FnMut(N) -> Option<N2>,
+        G: FnMut(N, N, E) -> Option<E2>,
+        N2: NodeTrait,
+        S: Default,
+    {
+        let mut graph = GraphMap::with_capacity_default(0, 0);
+        let mut mapped = HashMap::with_capacity(self.node_count());
+        for &n in self.nodes.keys() {
+            if let Some(m) = node_map(n) {
+                mapped.insert(n, graph.add_node(m));
+            }
+        }
+        for ((a, b), weight) in self.edges {
+            if let (Some(&ma), Some(&mb)) = (mapped.get(&a), mapped.get(&b)) {
+                if let Some(w) = edge_map(a, b, weight) {
+                    graph.add_edge(ma, mb, w);
+                }
+            }
+        }
+        graph
+    }
+
+    /// Decompose the graph into its nodes and its edges with their weights.
+    ///
+    /// Nodes and edges are returned in order, with the endpoints of each edge
+    /// as returned by [`all_edges`](#method.all_edges), so that passing them
+    /// back to [`add_node`](#method.add_node) and
+    /// [`from_edges`](#method.from_edges) rebuilds the same graph.
+    ///
+    /// ```
+    /// use petgraph::graphmap::UnGraphMap;
+    ///
+    /// let g = UnGraphMap::<_, u8>::from_edges(&[(2, 1, 7)]);
+    /// let (nodes, edges) = g.into_nodes_edges();
+    /// assert_eq!(nodes, vec![2, 1]);
+    /// assert_eq!(edges, vec![(1, 2, 7)]);
+    /// ```
+    pub fn into_nodes_edges(self) -> (Vec<N>, Vec<(N, N, E)>) {
+        let nodes = self.nodes.into_iter().map(|(n, _)| n).collect();
+        let edges = self
+            .edges
+            .into_iter()
+            .map(|((a, b), weight)| (a, b, weight))
+            .collect();
+        (nodes, edges)
+    }
+
+    /// Merge the nodes and edges of `other` into this graph.
+    ///
+    /// Nodes and edges of `other` that are missing from this graph are added.
+    /// When an edge is in both graphs, `combine` is called with the weight in
+    /// this graph and the weight in `other` to update it. Self loops are
+    /// merged like any other edge.
+    ///
+    /// Computes in **O(|V'| + |E'|)** time (average), where **V'** and **E'**
+    /// are the nodes and edges of `other`.
+    ///
+    /// ```
+    /// use petgraph::graphmap::UnGraphMap;
+    ///
+    /// let mut g = UnGraphMap::<_, u32>::from_edges(&[(1, 2, 1), (2, 3, 1)]);
+    /// let h = UnGraphMap::<_, u32>::from_edges(&[(3, 2, 10), (3, 4, 10)]);
+    /// g.union(h, |w, other| *w += other);
+    /// assert_eq!(g.edge_weight(1, 2), Some(&1));
+    /// assert_eq!(g.edge_weight(2, 3), Some(&11));
+    /// assert_eq!(g.edge_weight(3, 4), Some(&10));
+    /// ```
+    pub fn union<F>(&mut self, other: GraphMap<N, E, Ty, S>, mut combine: F)
+    where
+        F: FnMut(&mut E, E),
+    {
+        self.reserve_nodes(other.node_count());
+        for &n in other.nodes.keys() {
+            self.add_node(n);
+        }
+        for ((a, b), weight) in other.edges {
+            match self.edges.get_mut(&(a, b)) {
+                Some(existing) => combine(existing, weight),
+                None => {
+                    self.add_edge(a, b, weight);
+                }
+            }
+        }
+    }
+
+    /// Return the subgraph induced by `nodes`: the given nodes together with
+    /// all edges between two of them.
+    ///
+    /// Nodes that are not part of the graph are ignored. The nodes of the
+    /// result are in the order given, and the edges keep their order.
+    ///
+    /// ```
+    /// use petgraph::graphmap::UnGraphMap;
+    ///
+    /// let g = UnGraphMap::<_, ()>::from_edges(&[(1, 2), (2, 3), (3, 1), (3, 4)]);
+    /// let sub = g.subgraph(vec![1, 3, 4, 9]);
+    /// assert_eq!(sub.nodes().collect::<Vec<_>>(), vec![1, 3, 4]);
+    /// assert_eq!(sub.edge_count(), 2);
+    /// ```
+    pub fn subgraph<I>(&self, nodes: I) -> GraphMap<N, E, Ty, S>
+    where
+        I: IntoIterator<Item = N>,
+        E: Clone,
+        S: Default,
+    {
+        let mut graph = GraphMap::with_capacity_default(0, 0);
+        for n in nodes {
+            if self.contains_node(n) {
+                graph.add_node(n);
+            }
+        }
+        for (&(a, b), weight) in &self.edges {
+            if graph.contains_node(a) && graph.contains_node(b) {
+                graph.add_edge(a, b, weight.clone());
+            }
+        }
+        graph
+    }
+
+    /// Return the complement of the graph: a graph over the same nodes that
+    /// has an edge exactly where this graph does not.
+    ///
+    /// Directed graphs consider ordered pairs of nodes and undirected graphs
+    /// unordered pairs. Self loops are only part of the complement if
+    /// `self_loops` is `true`.
+    ///
+    /// Computes in **O(|V|²)** time (average).
+    ///
+    /// ```
+    /// use petgraph::graphmap::UnGraphMap;
+    ///
+    /// let g = UnGraphMap::<_, ()>::from_edges(&[(1, 2), (2, 3)]);
+    /// let c = g.complement(false);
+    /// assert_eq!(c.all_edges().collect::<Vec<_>>(), vec![(1, 3, &())]);
+    /// ```
+    pub fn complement(&self, self_loops: bool) -> GraphMap<N, (), Ty, S>
+    where
+        S: Default,
+    {
+        let mut graph = GraphMap::with_capacity_default(self.node_count(), 0);
+        for &n in self.nodes.keys() {
+            graph.add_node(n);
+        }
+        for (i, &a) in self.nodes.keys().enumerate() {
+            for (j, &b) in self.nodes.keys().enumerate() {
+                if (i == j && !self_loops) || (i > j && !Ty::is_directed()) {
+                    continue;
+                }
+                if !self.contains_edge(a, b) {
+                    graph.add_edge(a, b, ());
+                }
+            }
+        }
+        graph
+    }
+
+    /// Return the nodes of the graph in insertion order, and a square boolean
+    /// matrix whose entry `[i][j]` is `true` if there is an edge from the
+    /// `i`th to the `j`th node.
+    ///
+    /// The matrix of an undirected graph is symmetric. This is the inverse of
+    /// [`from_adjacency_matrix`](#method.from_adjacency_matrix).
+    ///
+    /// Computes in **O(|V|² + |E|)** time (average).
+    ///
+    /// ```
+    /// use petgraph::graphmap::UnGraphMap;
+    ///
+    /// let g = UnGraphMap::<_, ()>::from_edges(&[('a', 'b'), ('c', 'b')]);
+    /// let (labels, matrix) = g.to_adjacency_matrix();
+    /// assert_eq!(labels, vec!['a', 'b', 'c']);
+    /// assert_eq!(matrix, vec![
+    ///     vec![false, true, false],
+    ///     vec![true, false, true],
+    ///     vec![false, true, false],
+    /// ]);
+    /// ```
+    pub fn to_adjacency_matrix(&self) -> (Vec<N>, Vec<Vec<bool>>) {
+        let n = self.node_count();
+        let mut matrix = vec![vec![false; n]; n];
+        for &(a, b) in self.edges.keys() {
+            let i = self.nodes.get_index_of(&a).unwrap();
+            let j = self.nodes.get_index_of(&b).unwrap();
+            matrix[i][j] = true;
+            if !Ty::is_directed() {
+                matrix[j][i] = true;
+            }
+        }
+        (self.nodes().collect(), matrix)
     }
 
-    /// Return an iterator over all edges of the graph with their weight in arbitrary order.
+    /// Return an undirected copy of the graph.
     ///
-    /// Iterator element type is `(N, N, &E)`
-    pub fn all_edges(&self) -> AllEdges<N, E, Ty> {
-        AllEdges {
-            inner: self.edges.iter(),
-            ty: self.ty,
+    /// Every edge `(a, b)` becomes the undirected edge between `a` and `b`.
+    /// When a directed graph has edges in both directions between two
+    /// nodes, the edge that comes first in [`all_edges`](#method.all_edges)
+    /// order is kept and the other one is merged into it with
+    /// `combine(existing, other)`. An undirected graph is copied as is.
+    ///
+    /// Computes in **O(|V| + |E|)** time (average).
+    ///
+    /// ```
+    /// use petgraph::graphmap::DiGraphMap;
+    ///
+    /// let g = DiGraphMap::<_, u32>::from_edges(&[(1, 2, 1), (2, 1, 2), (2, 3, 4)]);
+    /// let u = g.to_undirected(|existing, other| *existing += other);
+    /// assert_eq!(u.edge_count(), 2);
+    /// assert_eq!(u.edge_weight(2, 1), Some(&3));
+    /// assert_eq!(u.edge_weight(3, 2), Some(&4));
+    /// ```
+    pub fn to_undirected<F>(&self, mut combine: F) -> GraphMap<N, E, Undirected, S>
+    where
+        F: FnMut(&mut E, E),
+        E: Clone,
+        S: Default,
+    {
+        let mut graph = GraphMap::with_capacity_default(self.node_count(), self.edge_count());
+        for &n in self.nodes.keys() {
+            graph.add_node(n);
+        }
+        for (a, b, weight) in self.all_edges() {
+            match graph.edge_weight_mut(a, b) {
+                Some(existing) => combine(existing, weight.clone()),
+                None => {
+                    graph.add_edge(a, b, weight.clone());
+                }
+            }
         }
+        graph
     }
 
-    /// Return an iterator over all edges of the graph in arbitrary order, with a mutable reference
-    /// to their weight.
+    /// Return a directed copy of the graph.
     ///
-    /// Iterator element type is `(N, N, &mut E)`
-    pub fn all_edges_mut(&mut self) -> AllEdgesMut<N, E, Ty> {
-        AllEdgesMut {
-            inner: self.edges.iter_mut(),
-            ty: self.ty,
+    /// Every undirected edge between `a` and `b` becomes the two directed
+    /// edges `(a, b)` and `(b, a)` with the same weight, except for self
+    /// loops which stay a single edge. A directed graph is copied as is.
+    ///
+    /// Computes in **O(|V| + |E|)** time (average).
+    ///
+    /// ```
+    /// use petgraph::graphmap::UnGraphMap;
+    ///
+    /// let g = UnGraphMap::<_, u32>::from_edges(&[(1, 2, 1), (2, 2, 2)]);
+    /// let d = g.to_directed();
+    /// assert_eq!(d.edge_count(), 3);
+    /// assert_eq!(d.edge_weight(1, 2), Some(&1));
+    /// assert_eq!(d.edge_weight(2, 1), Some(&1));
+    /// ```
+    pub fn to_directed(&self) -> GraphMap<N, E, Directed, S>
+    where
+        E: Clone,
+        S: Default,
+    {
+        let edge_count = if Ty::is_directed() {
+            self.edge_count()
+        } else {
+            2 * self.edge_count()
+        };
+        let mut graph = GraphMap::with_capacity_default(self.node_count(), edge_count);
+        for &n in self.nodes.keys() {
+            graph.add_node(n);
+        }
+        for (a, b, weight) in self.all_edges() {
+            graph.add_edge(a, b, weight.clone());
+            if !Ty::is_directed() && a != b {
+                graph.add_edge(b, a, weight.clone());
+            }
         }
+        graph
     }
 
     /// Return a `Graph` that corresponds to this `GraphMap`.
@@ -542,16 +2829,18 @@ where
     ///
     /// **Warning**: Nodes with the same weight are merged and only the last parallel edge
     /// is kept. Node and edge indices of the `Graph` are lost. Only use this function
-    /// if the node weights are distinct and there are no parallel edges.
+    /// if the node weights are distinct and there are no parallel edges, or
+    /// use `GraphMap::try_from` to detect them.
     ///
     /// Computes in **O(|V| + |E|)** time (average).
     pub fn from_graph<Ix>(graph: Graph<N, E, Ty, Ix>) -> Self
     where
         Ix: crate::graph::IndexType,
         E: Clone,
+        S: Default,
     {
-        let mut new_graph: GraphMap<N, E, Ty> =
-            GraphMap::with_capacity(graph.node_count(), graph.edge_count());
+        let mut new_graph: GraphMap<N, E, Ty, S> =
+            GraphMap::with_capacity_default(graph.node_count(), graph.edge_count());
 
         for node in graph.raw_nodes() {
             new_graph.add_node(node.weight);
@@ -568,14 +2857,144 @@ where
 
         new_graph
     }
+
+    /// Return a `StableGraph` that corresponds to this `GraphMap`.
+    ///
+    /// As with [`into_graph`](#method.into_graph), node and edge indices in
+    /// the `StableGraph` have nothing in common with the `GraphMap`s node
+    /// weights `N`, which are used as node weights in the resulting
+    /// `StableGraph`. The index type is user-chosen.
+    ///
+    /// Computes in **O(|V| + |E|)** time (average).
+    ///
+    /// **Panics** if the number of nodes or edges does not fit with
+    /// the resulting graph's index type.
+    #[cfg(feature = "stable_graph")]
+    pub fn into_stable_graph<Ix>(self) -> StableGraph<N, E, Ty, Ix>
+    where
+        Ix: crate::graph::IndexType,
+    {
+        StableGraph::from(self.into_graph())
+    }
+
+    /// Creates a `GraphMap` that corresponds to the given `StableGraph`.
+    ///
+    /// Vacant node and edge slots of the `StableGraph` are skipped.
+    ///
+    /// **Warning**: Nodes with the same weight are merged and only the last parallel edge
+    /// is kept. Node and edge indices of the `StableGraph` are lost. Only use this function
+    /// if the node weights are distinct and there are no parallel edges.
+    ///
+    /// Computes in **O(|V| + |E|)** time (average).
+    ///
+    /// ```
+    /// use petgraph::graphmap::DiGraphMap;
+    /// use petgraph::stable_graph::StableDiGraph;
+    ///
+    /// let mut sg = StableDiGraph::<_, u32>::new();
+    /// let a = sg.add_node('a');
+    /// let b = sg.add_node('b');
+    /// let c = sg.add_node('c');
+    /// sg.add_edge(a, c, 1);
+    /// sg.add_edge(b, c, 2);
+    /// sg.remove_node(b);
+    ///
+    /// let g = DiGraphMap::<_, u32>::from_stable_graph(sg);
+    /// assert_eq!(g.nodes().collect::<Vec<_>>(), vec!['a', 'c']);
+    /// assert_eq!(g.all_edges().collect::<Vec<_>>(), vec![('a', 'c', &1)]);
+    /// ```
+    #[cfg(feature = "stable_graph")]
+    pub fn from_stable_graph<Ix>(graph: StableGraph<N, E, Ty, Ix>) -> Self
+    where
+        Ix: crate::graph::IndexType,
+        S: Default,
+    {
+        let (nodes, edges) = Graph::from(graph).into_nodes_edges();
+        let mut new_graph = GraphMap::with_capacity_default(nodes.len(), edges.len());
+        for node in &nodes {
+            new_graph.add_node(node.weight);
+        }
+        for edge in edges {
+            let a = nodes[edge.source().index()].weight;
+            let b = nodes[edge.target().index()].weight;
+            new_graph.add_edge(a, b, edge.weight);
+        }
+        new_graph
+    }
+}
+
+/// An error from converting a `Graph` into a `GraphMap` with `TryFrom`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConversionError {
+    /// Two nodes of the `Graph` have the same weight.
+    DuplicateNode,
+    /// Two edges of the `Graph` connect the same pair of nodes.
+    ParallelEdge,
+}
+
+/// Create a `GraphMap` that corresponds to the given `Graph`, without
+/// losing any nodes or edges.
+///
+/// Unlike [`GraphMap::from_graph`](struct.GraphMap.html#method.from_graph),
+/// return an error if two nodes have the same weight, or if two edges connect
+/// the same pair of nodes. For an undirected graph, the edges `a -> b` and
+/// `b -> a` are parallel.
+///
+/// Computes in **O(|V| + |E|)** time (average).
+///
+/// ```
+/// use petgraph::graph::DiGraph;
+/// use petgraph::graphmap::{ConversionError, DiGraphMap};
+/// use std::convert::TryFrom;
+///
+/// let mut g = DiGraph::<_, ()>::new();
+/// let a = g.add_node('a');
+/// let b = g.add_node('b');
+/// g.add_edge(a, b, ());
+/// let gm = DiGraphMap::<_, ()>::try_from(g.clone()).unwrap();
+/// assert!(gm.contains_edge('a', 'b'));
+///
+/// g.add_edge(a, b, ());
+/// let err = DiGraphMap::<_, ()>::try_from(g).unwrap_err();
+/// assert_eq!(err, ConversionError::ParallelEdge);
+/// ```
+impl<N, E, Ty, Ix, S> TryFrom<Graph<N, E, Ty, Ix>> for GraphMap<N, E, Ty, S>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+    Ix: crate::graph::IndexType,
+    S: BuildHasher + Default,
+{
+    type Error = ConversionError;
+
+    fn try_from(graph: Graph<N, E, Ty, Ix>) -> Result<Self, Self::Error> {
+        let (nodes, edges) = graph.into_nodes_edges();
+        let mut new_graph = GraphMap::with_capacity_default(nodes.len(), edges.len());
+        for node in &nodes {
+            if new_graph.contains_node(node.weight) {
+                return Err(ConversionError::DuplicateNode);
+            }
+            new_graph.add_node(node.weight);
+        }
+        for edge in edges {
+            let a = nodes[edge.source().index()].weight;
+            let b = nodes[edge.target().index()].weight;
+            if new_graph.contains_edge(a, b) {
+                return Err(ConversionError::ParallelEdge);
+            }
+            new_graph.add_edge(a, b, edge.weight);
+        }
+        Ok(new_graph)
+    }
 }
 
 /// Create a new `GraphMap` from an iterable of edges.
-impl<N, E, Ty, Item> FromIterator<Item> for GraphMap<N, E, Ty>
+impl<N, E, Ty, Item, S> FromIterator<Item> for GraphMap<N, E, Ty, S>
 where
     Item: IntoWeightedEdge<E, NodeId = N>,
     N: NodeTrait,
     Ty: EdgeType,
+    S: BuildHasher + Default,
 {
     fn from_iter<I>(iterable: I) -> Self
     where
@@ -583,7 +3002,7 @@ where
     {
         let iter = iterable.into_iter();
         let (low, _) = iter.size_hint();
-        let mut g = Self::with_capacity(0, low);
+        let mut g = Self::with_capacity_default(0, low);
         g.extend(iter);
         g
     }
@@ -592,11 +3011,12 @@ where
 /// Extend the graph from an iterable of edges.
 ///
 /// Nodes are inserted automatically to match the edges.
-impl<N, E, Ty, Item> Extend<Item> for GraphMap<N, E, Ty>
+impl<N, E, Ty, Item, S> Extend<Item> for GraphMap<N, E, Ty, S>
 where
     Item: IntoWeightedEdge<E, NodeId = N>,
     N: NodeTrait,
     Ty: EdgeType,
+    S: BuildHasher,
 {
     fn extend<I>(&mut self, iterable: I)
     where
@@ -621,6 +3041,22 @@ iterator_wrap! {
     iter: Cloned<Keys<'a, N, Vec<(N, CompactDirection)>>>,
 }
 
+iterator_wrap! {
+    impl (Iterator DoubleEndedIterator ExactSizeIterator) for
+    #[derive(Debug, Clone)]
+    struct EdgeWeights <'a, N, E> where { N: 'a + NodeTrait, E: 'a }
+    item: &'a E,
+    iter: Values<'a, (N, N), E>,
+}
+
+iterator_wrap! {
+    impl (Iterator DoubleEndedIterator ExactSizeIterator) for
+    #[derive(Debug)]
+    struct EdgeWeightsMut <'a, N, E> where { N: 'a + NodeTrait, E: 'a }
+    item: &'a mut E,
+    iter: ValuesMut<'a, (N, N), E>,
+}
+
 #[derive(Debug, Clone)]
 pub struct Neighbors<'a, N, Ty = Undirected>
 where
@@ -701,22 +3137,73 @@ where
     }
 }
 
+/// Iterator over the nodes of a `GraphMap` in breadth-first order.
+///
+/// Created with [`GraphMap::bfs`](struct.GraphMap.html#method.bfs).
+#[derive(Clone)]
+pub struct BfsNodes<'a, N, E: 'a, Ty, S = RandomState>
+where
+    N: 'a + NodeTrait,
+    Ty: EdgeType,
+{
+    graph: &'a GraphMap<N, E, Ty, S>,
+    bfs: visit::Bfs<N, HashSet<N, S>>,
+}
+
+impl<'a, N, E, Ty, S> Iterator for BfsNodes<'a, N, E, Ty, S>
+where
+    N: 'a + NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher + Default,
+{
+    type Item = N;
+    fn next(&mut self) -> Option<N> {
+        self.bfs.next(self.graph)
+    }
+}
+
+/// Iterator over the nodes of a `GraphMap` in depth-first preorder.
+///
+/// Created with [`GraphMap::dfs`](struct.GraphMap.html#method.dfs).
+#[derive(Clone)]
+pub struct DfsNodes<'a, N, E: 'a, Ty, S = RandomState>
+where
+    N: 'a + NodeTrait,
+    Ty: EdgeType,
+{
+    graph: &'a GraphMap<N, E, Ty, S>,
+    dfs: visit::Dfs<N, HashSet<N, S>>,
+}
+
+impl<'a, N, E, Ty, S> Iterator for DfsNodes<'a, N, E, Ty, S>
+where
+    N: 'a + NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher + Default,
+{
+    type Item = N;
+    fn next(&mut self) -> Option<N> {
+        self.dfs.next(self.graph)
+    }
+}
+
 #[derive(Debug, Clone)]
-pub struct Edges<'a, N, E: 'a, Ty>
+pub struct Edges<'a, N, E: 'a, Ty, S = RandomState>
 where
     N: 'a + NodeTrait,
     Ty: EdgeType,
 {
     from: N,
-    edges: &'a IndexMap<(N, N), E>,
+    edges: &'a IndexMap<(N, N), E, S>,
     iter: Neighbors<'a, N, Ty>,
 }
 
-impl<'a, N, E, Ty> Iterator for Edges<'a, N, E, Ty>
+impl<'a, N, E, Ty, S> Iterator for Edges<'a, N, E, Ty, S>
 where
     N: 'a + NodeTrait,
     E: 'a,
     Ty: EdgeType,
+    S: BuildHasher,
 {
     type Item = (N, N, &'a E);
     fn next(&mut self) -> Option<Self::Item> {
@@ -734,22 +3221,23 @@ where
 }
 
 #[derive(Debug, Clone)]
-pub struct EdgesDirected<'a, N, E: 'a, Ty>
+pub struct EdgesDirected<'a, N, E: 'a, Ty, S = RandomState>
 where
     N: 'a + NodeTrait,
     Ty: EdgeType,
 {
     from: N,
     dir: Direction,
-    edges: &'a IndexMap<(N, N), E>,
+    edges: &'a IndexMap<(N, N), E, S>,
     iter: NeighborsDirected<'a, N, Ty>,
 }
 
-impl<'a, N, E, Ty> Iterator for EdgesDirected<'a, N, E, Ty>
+impl<'a, N, E, Ty, S> Iterator for EdgesDirected<'a, N, E, Ty, S>
 where
     N: 'a + NodeTrait,
     E: 'a,
     Ty: EdgeType,
+    S: BuildHasher,
 {
     type Item = (N, N, &'a E);
     fn next(&mut self) -> Option<Self::Item> {
@@ -878,11 +3366,138 @@ where
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct SelfLoops<'a, N, E: 'a, S = RandomState>
+where
+    N: 'a + NodeTrait,
+{
+    nodes: Keys<'a, N, Vec<(N, CompactDirection)>>,
+    edges: &'a IndexMap<(N, N), E, S>,
+}
+
+impl<'a, N, E, S> Iterator for SelfLoops<'a, N, E, S>
+where
+    N: 'a + NodeTrait,
+    E: 'a,
+    S: BuildHasher,
+{
+    type Item = (N, &'a E);
+    fn next(&mut self) -> Option<Self::Item> {
+        let edges = self.edges;
+        (&mut self.nodes)
+            .filter_map(|&n| edges.get(&(n, n)).map(|weight| (n, weight)))
+            .next()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.nodes.size_hint().1)
+    }
+}
+
+pub struct EdgesDirectedMut<'a, N, E: 'a, Ty>
+where
+    N: 'a + NodeTrait,
+{
+    /// Map indices and endpoints of the edges to produce, by increasing index.
+    targets: std::vec::IntoIter<(usize, N, N)>,
+    /// Map index of the next element of `inner`.
+    position: usize,
+    inner: IndexMapIterMut<'a, (N, N), E>,
+    ty: PhantomData<Ty>,
+}
+
+impl<'a, N, E, Ty> Iterator for EdgesDirectedMut<'a, N, E, Ty>
+where
+    N: 'a + NodeTrait,
+    E: 'a,
+    Ty: EdgeType,
+{
+    type Item = (N, N, &'a mut E);
+    fn next(&mut self) -> Option<Self::Item> {
+        let (index, a, b) = self.targets.next()?;
+        let (_, weight) = self.inner.nth(index - self.position)?;
+        self.position = index + 1;
+        Some((a, b, weight))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.targets.size_hint()
+    }
+}
+
+/// A view into the edge between two nodes of a `GraphMap`, which may either
+/// exist or not.
+///
+/// This is constructed by [`GraphMap::edge_entry`](struct.GraphMap.html#method.edge_entry).
+pub struct EdgeEntry<'a, N, E: 'a, Ty, S = RandomState>
+where
+    N: 'a + NodeTrait,
+{
+    a: N,
+    b: N,
+    entry: Entry<'a, (N, N), E>,
+    nodes: &'a mut IndexMap<N, Vec<(N, CompactDirection)>, S>,
+    ty: PhantomData<Ty>,
+}
+
+impl<'a, N, E, Ty, S> EdgeEntry<'a, N, E, Ty, S>
+where
+    N: 'a + NodeTrait,
+    E: 'a,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    /// Return the endpoints of the edge, as they were passed to `edge_entry`.
+    pub fn nodes(&self) -> (N, N) {
+        (self.a, self.b)
+    }
+
+    /// Modify the weight of the edge with `f` if it exists.
+    pub fn and_modify<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(&mut E),
+    {
+        if let Entry::Occupied(ref mut entry) = self.entry {
+            f(entry.get_mut());
+        }
+        self
+    }
+
+    /// Return a mutable reference to the weight of the edge, first adding the
+    /// edge with weight `default` if it does not exist.
+    pub fn or_insert(self, default: E) -> &'a mut E {
+        self.or_insert_with(|| default)
+    }
+
+    /// Return a mutable reference to the weight of the edge, first adding the
+    /// edge with weight `default()` if it does not exist.
+    pub fn or_insert_with<F>(self, default: F) -> &'a mut E
+    where
+        F: FnOnce() -> E,
+    {
+        match self.entry {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                GraphMap::<N, E, Ty, S>::add_links(self.nodes, self.a, self.b);
+                entry.insert(default())
+            }
+        }
+    }
+
+    /// Return a mutable reference to the weight of the edge, first adding the
+    /// edge with the default weight if it does not exist.
+    pub fn or_default(self) -> &'a mut E
+    where
+        E: Default,
+    {
+        self.or_insert_with(E::default)
+    }
+}
+
 /// Index `GraphMap` by node pairs to access edge weights.
-impl<N, E, Ty> Index<(N, N)> for GraphMap<N, E, Ty>
+impl<N, E, Ty, S> Index<(N, N)> for GraphMap<N, E, Ty, S>
 where
     N: NodeTrait,
     Ty: EdgeType,
+    S: BuildHasher,
 {
     type Output = E;
     fn index(&self, index: (N, N)) -> &E {
@@ -893,10 +3508,11 @@ where
 }
 
 /// Index `GraphMap` by node pairs to access edge weights.
-impl<N, E, Ty> IndexMut<(N, N)> for GraphMap<N, E, Ty>
+impl<N, E, Ty, S> IndexMut<(N, N)> for GraphMap<N, E, Ty, S>
 where
     N: NodeTrait,
     Ty: EdgeType,
+    S: BuildHasher,
 {
     fn index_mut(&mut self, index: (N, N)) -> &mut E {
         let index = Self::edge_key(index.0, index.1);
@@ -906,13 +3522,14 @@ where
 }
 
 /// Create a new empty `GraphMap`.
-impl<N, E, Ty> Default for GraphMap<N, E, Ty>
+impl<N, E, Ty, S> Default for GraphMap<N, E, Ty, S>
 where
     N: NodeTrait,
     Ty: EdgeType,
+    S: BuildHasher + Default,
 {
     fn default() -> Self {
-        GraphMap::with_capacity(0, 0)
+        GraphMap::with_capacity_default(0, 0)
     }
 }
 
@@ -922,8 +3539,52 @@ where
 /// in particular in the combination where the node type for
 /// `GraphMap` is something of type for example `Ptr(&Cell<T>)`,
 /// with the `Cell<T>` being `TypedArena` allocated.
+///
+/// Node keys of a `GraphMap` must be `Copy`, so there can't be a `Ptr`
+/// variant that hands out `&mut T`. To mutate the payload of a node, put it
+/// in a `Cell` or `RefCell`; since `Ptr` compares by address, mutating the
+/// payload doesn't change the identity of the node.
+///
+/// ```
+/// use petgraph::graphmap::{DiGraphMap, Ptr};
+/// use std::cell::RefCell;
+///
+/// let a = RefCell::new(String::from("a"));
+/// let b = RefCell::new(String::from("b"));
+/// let mut g = DiGraphMap::new();
+/// g.add_edge(Ptr(&a), Ptr(&b), ());
+/// for node in g.nodes() {
+///     node.borrow_mut().push('!');
+/// }
+/// assert_eq!(*a.borrow(), "a!");
+/// assert!(g.contains_edge(Ptr(&a), Ptr(&b)));
+/// ```
 pub struct Ptr<'b, T: 'b>(pub &'b T);
 
+impl<'b, T> Ptr<'b, T> {
+    /// Create a new `Ptr` to `value`.
+    ///
+    /// Two `Ptr`s are equal only if they point to the same value, not if
+    /// the values they point to are equal.
+    ///
+    /// ```
+    /// use petgraph::graphmap::Ptr;
+    ///
+    /// let (x, y) = (1, 1);
+    /// assert_eq!(Ptr::new(&x), Ptr::new(&x));
+    /// assert_ne!(Ptr::new(&x), Ptr::new(&y));
+    /// ```
+    pub fn new(value: &'b T) -> Self {
+        Ptr(value)
+    }
+
+    /// Return the raw pointer to the value, which is what the `Ptr` is
+    /// compared, ordered and hashed by.
+    pub fn as_ptr(&self) -> *const T {
+        self.0
+    }
+}
+
 impl<'b, T> Copy for Ptr<'b, T> {}
 impl<'b, T> Clone for Ptr<'b, T> {
     fn clone(&self) -> Self {
@@ -1029,49 +3690,54 @@ where
     }
 }
 
-impl<N, E, Ty> visit::GraphBase for GraphMap<N, E, Ty>
+impl<N, E, Ty, S> visit::GraphBase for GraphMap<N, E, Ty, S>
 where
     N: Copy + PartialEq,
+    S: BuildHasher,
 {
     type NodeId = N;
     type EdgeId = (N, N);
 }
 
-impl<N, E, Ty> visit::Data for GraphMap<N, E, Ty>
+impl<N, E, Ty, S> visit::Data for GraphMap<N, E, Ty, S>
 where
     N: Copy + PartialEq,
     Ty: EdgeType,
+    S: BuildHasher,
 {
     type NodeWeight = N;
     type EdgeWeight = E;
 }
 
-impl<N, E, Ty> visit::Visitable for GraphMap<N, E, Ty>
+impl<N, E, Ty, S> visit::Visitable for GraphMap<N, E, Ty, S>
 where
     N: Copy + Ord + Hash,
     Ty: EdgeType,
+    S: BuildHasher + Default,
 {
-    type Map = HashSet<N>;
-    fn visit_map(&self) -> HashSet<N> {
-        HashSet::with_capacity(self.node_count())
+    type Map = HashSet<N, S>;
+    fn visit_map(&self) -> HashSet<N, S> {
+        HashSet::with_capacity_and_hasher(self.node_count(), S::default())
     }
     fn reset_map(&self, map: &mut Self::Map) {
         map.clear();
     }
 }
 
-impl<N, E, Ty> visit::GraphProp for GraphMap<N, E, Ty>
+impl<N, E, Ty, S> visit::GraphProp for GraphMap<N, E, Ty, S>
 where
     N: NodeTrait,
     Ty: EdgeType,
+    S: BuildHasher,
 {
     type EdgeType = Ty;
 }
 
-impl<'a, N, E, Ty> visit::IntoNodeReferences for &'a GraphMap<N, E, Ty>
+impl<'a, N, E, Ty, S> visit::IntoNodeReferences for &'a GraphMap<N, E, Ty, S>
 where
     N: NodeTrait,
     Ty: EdgeType,
+    S: BuildHasher,
 {
     type NodeRef = (N, &'a N);
     type NodeReferences = NodeReferences<'a, N, E, Ty>;
@@ -1084,10 +3750,11 @@ where
     }
 }
 
-impl<'a, N, E: 'a, Ty> visit::IntoNodeIdentifiers for &'a GraphMap<N, E, Ty>
+impl<'a, N, E: 'a, Ty, S> visit::IntoNodeIdentifiers for &'a GraphMap<N, E, Ty, S>
 where
     N: NodeTrait,
     Ty: EdgeType,
+    S: BuildHasher,
 {
     type NodeIdentifiers = NodeIdentifiers<'a, N, E, Ty>;
 
@@ -1100,27 +3767,28 @@ where
     }
 }
 
-impl<N, E, Ty> visit::NodeCount for GraphMap<N, E, Ty>
+impl<N, E, Ty, S> visit::NodeCount for GraphMap<N, E, Ty, S>
 where
     N: NodeTrait,
     Ty: EdgeType,
+    S: BuildHasher,
 {
     fn node_count(&self) -> usize {
         (*self).node_count()
     }
 }
 
-impl<N, E, Ty> visit::NodeIndexable for GraphMap<N, E, Ty>
+impl<N, E, Ty, S> visit::NodeIndexable for GraphMap<N, E, Ty, S>
 where
     N: NodeTrait,
     Ty: EdgeType,
+    S: BuildHasher,
 {
     fn node_bound(&self) -> usize {
         self.node_count()
     }
     fn to_index(&self, ix: Self::NodeId) -> usize {
-        let (i, _, _) = self.nodes.get_full(&ix).unwrap();
-        i
+        self.node_index(ix).unwrap()
     }
     fn from_index(&self, ix: usize) -> Self::NodeId {
         assert!(
@@ -1133,17 +3801,19 @@ where
     }
 }
 
-impl<N, E, Ty> visit::NodeCompactIndexable for GraphMap<N, E, Ty>
+impl<N, E, Ty, S> visit::NodeCompactIndexable for GraphMap<N, E, Ty, S>
 where
     N: NodeTrait,
     Ty: EdgeType,
+    S: BuildHasher,
 {
 }
 
-impl<'a, N: 'a, E, Ty> visit::IntoNeighbors for &'a GraphMap<N, E, Ty>
+impl<'a, N: 'a, E, Ty, S> visit::IntoNeighbors for &'a GraphMap<N, E, Ty, S>
 where
     N: Copy + Ord + Hash,
     Ty: EdgeType,
+    S: BuildHasher,
 {
     type Neighbors = Neighbors<'a, N, Ty>;
     fn neighbors(self, n: Self::NodeId) -> Self::Neighbors {
@@ -1151,10 +3821,11 @@ where
     }
 }
 
-impl<'a, N: 'a, E, Ty> visit::IntoNeighborsDirected for &'a GraphMap<N, E, Ty>
+impl<'a, N: 'a, E, Ty, S> visit::IntoNeighborsDirected for &'a GraphMap<N, E, Ty, S>
 where
     N: Copy + Ord + Hash,
     Ty: EdgeType,
+    S: BuildHasher,
 {
     type NeighborsDirected = NeighborsDirected<'a, N, Ty>;
     fn neighbors_directed(self, n: N, dir: Direction) -> Self::NeighborsDirected {
@@ -1162,10 +3833,11 @@ where
     }
 }
 
-impl<N, E, Ty> visit::EdgeIndexable for GraphMap<N, E, Ty>
+impl<N, E, Ty, S> visit::EdgeIndexable for GraphMap<N, E, Ty, S>
 where
     N: NodeTrait,
     Ty: EdgeType,
+    S: BuildHasher,
 {
     fn edge_bound(&self) -> usize {
         self.edge_count()
@@ -1187,32 +3859,35 @@ where
     }
 }
 
-impl<'a, N: 'a, E: 'a, Ty> visit::IntoEdges for &'a GraphMap<N, E, Ty>
+impl<'a, N: 'a, E: 'a, Ty, S> visit::IntoEdges for &'a GraphMap<N, E, Ty, S>
 where
     N: NodeTrait,
     Ty: EdgeType,
+    S: BuildHasher,
 {
-    type Edges = Edges<'a, N, E, Ty>;
+    type Edges = Edges<'a, N, E, Ty, S>;
     fn edges(self, a: Self::NodeId) -> Self::Edges {
         self.edges(a)
     }
 }
 
-impl<'a, N: 'a, E: 'a, Ty> visit::IntoEdgesDirected for &'a GraphMap<N, E, Ty>
+impl<'a, N: 'a, E: 'a, Ty, S> visit::IntoEdgesDirected for &'a GraphMap<N, E, Ty, S>
 where
     N: NodeTrait,
     Ty: EdgeType,
+    S: BuildHasher,
 {
-    type EdgesDirected = EdgesDirected<'a, N, E, Ty>;
+    type EdgesDirected = EdgesDirected<'a, N, E, Ty, S>;
     fn edges_directed(self, a: Self::NodeId, dir: Direction) -> Self::EdgesDirected {
         self.edges_directed(a, dir)
     }
 }
 
-impl<'a, N: 'a, E: 'a, Ty> visit::IntoEdgeReferences for &'a GraphMap<N, E, Ty>
+impl<'a, N: 'a, E: 'a, Ty, S> visit::IntoEdgeReferences for &'a GraphMap<N, E, Ty, S>
 where
     N: NodeTrait,
     Ty: EdgeType,
+    S: BuildHasher,
 {
     type EdgeRef = (N, N, &'a E);
     type EdgeReferences = AllEdges<'a, N, E, Ty>;
@@ -1221,10 +3896,11 @@ where
     }
 }
 
-impl<N, E, Ty> visit::EdgeCount for GraphMap<N, E, Ty>
+impl<N, E, Ty, S> visit::EdgeCount for GraphMap<N, E, Ty, S>
 where
     N: NodeTrait,
     Ty: EdgeType,
+    S: BuildHasher,
 {
     #[inline]
     fn edge_count(&self) -> usize {
@@ -1233,10 +3909,11 @@ where
 }
 
 /// The `GraphMap` keeps an adjacency matrix internally.
-impl<N, E, Ty> visit::GetAdjacencyMatrix for GraphMap<N, E, Ty>
+impl<N, E, Ty, S> visit::GetAdjacencyMatrix for GraphMap<N, E, Ty, S>
 where
     N: Copy + Ord + Hash,
     Ty: EdgeType,
+    S: BuildHasher,
 {
     type AdjMatrix = ();
     #[inline]
@@ -1270,3 +3947,51 @@ where
         self.iter.drive_unindexed(c)
     }
 }
+
+/// A [ParallelIterator] over this graph's edges.
+#[cfg(feature = "rayon")]
+pub struct ParAllEdges<'a, N, E>
+where
+    N: NodeTrait + Send + Sync,
+    E: Sync,
+{
+    iter: ParIter<'a, (N, N), E>,
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, N, E> ParallelIterator for ParAllEdges<'a, N, E>
+where
+    N: NodeTrait + Send + Sync,
+    E: Sync,
+{
+    type Item = (N, N, &'a E);
+
+    fn drive_unindexed<C>(self, c: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        self.iter
+            .map(|(&(a, b), weight)| (a, b, weight))
+            .drive_unindexed(c)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        self.iter.opt_len()
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, N, E, Ty, S> IntoParallelIterator for &'a GraphMap<N, E, Ty, S>
+where
+    N: NodeTrait + Send + Sync,
+    E: Sync,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    type Item = (N, N, &'a E);
+    type Iter = ParAllEdges<'a, N, E>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.par_all_edges()
+    }
+}
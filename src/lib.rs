@@ -96,19 +96,33 @@
 //!   Defaults off. Enables serialization for ``Graph, StableGraph, GraphMap`` using
 //!   [`serde 1.0`](https://crates.io/crates/serde). May require a more recent version
 //!   of Rust than petgraph alone.
+//! * **bincode** -
+//!   Defaults off. Enables a compact binary encoding of ``GraphMap`` using
+//!   [`bincode 1.3`](https://crates.io/crates/bincode). Implies **serde-1**.
 //! * **graphmap** -
 //!   Defaults on. Enables [`GraphMap`](./graphmap/struct.GraphMap.html).
+//! * **multigraphmap** -
+//!   Defaults off. Enables [`MultiGraphMap`](./multigraphmap/struct.MultiGraphMap.html),
+//!   a `GraphMap` that allows parallel edges. Implies **graphmap**.
 //! * **stable_graph** -
 //!   Defaults on. Enables [`StableGraph`](./stable_graph/struct.StableGraph.html).
 //! * **matrix_graph** -
 //!   Defaults on. Enables [`MatrixGraph`](./matrix_graph/struct.MatrixGraph.html).
+//! * **rand** -
+//!   Defaults off. Enables randomized algorithms such as
+//!   [`uniform_spanning_tree`](./algo/fn.uniform_spanning_tree.html), using
+//!   [`rand`](https://crates.io/crates/rand).
 //!
 #![doc(html_root_url = "https://docs.rs/petgraph/0.4/")]
 
+#[cfg(feature = "bincode")]
+extern crate bincode;
 extern crate fixedbitset;
 #[cfg(feature = "graphmap")]
 extern crate indexmap;
 
+#[cfg(feature = "rand")]
+extern crate rand;
 #[cfg(feature = "serde-1")]
 extern crate serde;
 #[cfg(feature = "serde-1")]
@@ -146,6 +160,8 @@ mod iter_format;
 mod iter_utils;
 #[cfg(feature = "matrix_graph")]
 pub mod matrix_graph;
+#[cfg(feature = "multigraphmap")]
+pub mod multigraphmap;
 #[cfg(feature = "quickcheck")]
 mod quickcheck;
 #[cfg(feature = "serde-1")]
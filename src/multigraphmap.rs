@@ -0,0 +1,309 @@
+//! `MultiGraphMap<N, E, Ty>` is a `GraphMap` variant that allows parallel
+//! edges.
+
+use indexmap::map::Iter as IndexMapIter;
+use indexmap::IndexMap;
+use std::fmt;
+use std::hash::Hash;
+use std::slice::Iter;
+
+use crate::graphmap::{GraphMap, Neighbors, NeighborsDirected, NodeTrait, Nodes};
+use crate::{Directed, Direction, EdgeType, Undirected};
+
+/// A `MultiGraphMap` with undirected edges.
+pub type UnMultiGraphMap<N, E> = MultiGraphMap<N, E, Undirected>;
+/// A `MultiGraphMap` with directed edges.
+pub type DiMultiGraphMap<N, E> = MultiGraphMap<N, E, Directed>;
+
+/// `MultiGraphMap<N, E, Ty>` is a graph datastructure using an associative
+/// array of its node weights `N`, like [`GraphMap`](../graphmap/struct.GraphMap.html),
+/// but which allows several edges between the same pair of nodes.
+///
+/// Every edge is identified by its endpoints together with an edge id of
+/// type `usize`, which is returned by [`add_edge`](#method.add_edge). Edge ids
+/// are unique within the graph and are never reused.
+///
+/// The adjacency lists are those of a `GraphMap`, holding one entry per pair
+/// of adjacent nodes no matter how many parallel edges connect them.
+///
+/// Depends on crate feature `multigraphmap`.
+#[derive(Clone)]
+pub struct MultiGraphMap<N, E, Ty> {
+    /// Adjacency of the nodes, with the ids of the edges between each pair.
+    adjacency: GraphMap<N, Vec<usize>, Ty>,
+    edges: IndexMap<(N, N, usize), E>,
+    next_id: usize,
+}
+
+impl<N: Eq + Hash + fmt::Debug, E: fmt::Debug, Ty: EdgeType> fmt::Debug
+    for MultiGraphMap<N, E, Ty>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.edges.fmt(f)
+    }
+}
+
+impl<N, E, Ty> MultiGraphMap<N, E, Ty>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+{
+    /// Create a new `MultiGraphMap`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new `MultiGraphMap` with estimated capacity.
+    pub fn with_capacity(nodes: usize, edges: usize) -> Self {
+        MultiGraphMap {
+            adjacency: GraphMap::with_capacity(nodes, edges),
+            edges: IndexMap::with_capacity(edges),
+            next_id: 0,
+        }
+    }
+
+    #[inline]
+    fn edge_key(a: N, b: N, id: usize) -> (N, N, usize) {
+        let (a, b) = GraphMap::<N, Vec<usize>, Ty>::edge_key(a, b);
+        (a, b, id)
+    }
+
+    /// Whether the graph has directed edges.
+    pub fn is_directed(&self) -> bool {
+        Ty::is_directed()
+    }
+
+    /// Return the number of nodes in the graph.
+    pub fn node_count(&self) -> usize {
+        self.adjacency.node_count()
+    }
+
+    /// Return the number of edges in the graph, counting parallel edges
+    /// separately.
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Remove all nodes and edges
+    pub fn clear(&mut self) {
+        self.adjacency.clear();
+        self.edges.clear();
+    }
+
+    /// Add node `n` to the graph.
+    pub fn add_node(&mut self, n: N) -> N {
+        self.adjacency.add_node(n)
+    }
+
+    /// Return `true` if node `n` was removed.
+    ///
+    /// Computes in **O(|E|)** time, due to the removal of edges with other
+    /// nodes.
+    pub fn remove_node(&mut self, n: N) -> bool {
+        if !self.adjacency.remove_node(n) {
+            return false;
+        }
+        self.edges.retain(|&(a, b, _), _| a != n && b != n);
+        true
+    }
+
+    /// Return `true` if the node is contained in the graph.
+    pub fn contains_node(&self, n: N) -> bool {
+        self.adjacency.contains_node(n)
+    }
+
+    /// Add a new edge connecting `a` and `b` to the graph, with associated
+    /// data `weight`, and return its edge id. For a directed graph, the edge
+    /// is directed from `a` to `b`.
+    ///
+    /// Inserts nodes `a` and/or `b` if they aren't already part of the graph.
+    ///
+    /// Unlike `GraphMap::add_edge`, an existing edge between `a` and `b` is
+    /// never replaced; the new edge is added in parallel to it.
+    ///
+    /// ```
+    /// use petgraph::multigraphmap::DiMultiGraphMap;
+    ///
+    /// let mut g = DiMultiGraphMap::new();
+    /// let first = g.add_edge("x", "y", 1);
+    /// let second = g.add_edge("x", "y", 2);
+    /// assert_ne!(first, second);
+    /// assert_eq!(g.node_count(), 2);
+    /// assert_eq!(g.edge_count(), 2);
+    /// assert_eq!(g.edge_weight("x", "y", second), Some(&2));
+    /// ```
+    pub fn add_edge(&mut self, a: N, b: N, weight: E) -> usize {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.edges.insert(Self::edge_key(a, b, id), weight);
+        self.adjacency
+            .edge_weight_or_insert_with(a, b, Vec::new)
+            .push(id);
+        id
+    }
+
+    /// Remove the edge with id `id` between `a` and `b` from the graph and
+    /// return its weight.
+    ///
+    /// Return `None` if the edge didn't exist.
+    pub fn remove_edge(&mut self, a: N, b: N, id: usize) -> Option<E> {
+        let weight = self.edges.swap_remove(&Self::edge_key(a, b, id))?;
+        let ids = self.adjacency.edge_weight_mut(a, b).unwrap();
+        ids.retain(|&other| other != id);
+        if ids.is_empty() {
+            self.adjacency.remove_edge(a, b);
+        }
+        Some(weight)
+    }
+
+    /// Return `true` if there is at least one edge between `a` and `b`.
+    pub fn contains_edge(&self, a: N, b: N) -> bool {
+        self.adjacency.contains_edge(a, b)
+    }
+
+    /// Return an iterator over the nodes of the graph.
+    ///
+    /// Iterator element type is `N`.
+    pub fn nodes(&self) -> Nodes<'_, N> {
+        self.adjacency.nodes()
+    }
+
+    /// Return an iterator of all nodes with an edge starting from `a`.
+    ///
+    /// Each neighbor is produced once, even if there are parallel edges
+    /// to it.
+    ///
+    /// - `Directed`: Outgoing edges from `a`.
+    /// - `Undirected`: All edges from or to `a`.
+    ///
+    /// Produces an empty iterator if the node doesn't exist.<br>
+    /// Iterator element type is `N`.
+    pub fn neighbors(&self, a: N) -> Neighbors<'_, N, Ty> {
+        self.adjacency.neighbors(a)
+    }
+
+    /// Return an iterator of all neighbors that have an edge between them and
+    /// `a`, in the specified direction.
+    ///
+    /// Each neighbor is produced once, even if there are parallel edges
+    /// to it.
+    ///
+    /// Produces an empty iterator if the node doesn't exist.<br>
+    /// Iterator element type is `N`.
+    pub fn neighbors_directed(&self, a: N, dir: Direction) -> NeighborsDirected<'_, N, Ty> {
+        self.adjacency.neighbors_directed(a, dir)
+    }
+
+    /// Return an iterator over all the edges connecting `a` and `b`, in the
+    /// order they were added.
+    ///
+    /// - `Directed`: Edges from `a` to `b`.
+    /// - `Undirected`: All edges between `a` and `b`.
+    ///
+    /// Iterator element type is `(usize, &E)`, the edge id and its weight.
+    ///
+    /// ```
+    /// use petgraph::multigraphmap::UnMultiGraphMap;
+    ///
+    /// let mut g = UnMultiGraphMap::new();
+    /// g.add_edge(1, 2, "a");
+    /// g.add_edge(2, 1, "b");
+    /// let weights: Vec<_> = g.edges_connecting(1, 2).map(|(_, w)| *w).collect();
+    /// assert_eq!(weights, vec!["a", "b"]);
+    /// ```
+    pub fn edges_connecting(&self, a: N, b: N) -> EdgesConnecting<'_, N, E, Ty> {
+        EdgesConnecting {
+            from: a,
+            to: b,
+            ids: match self.adjacency.edge_weight(a, b) {
+                Some(ids) => ids.iter(),
+                None => [].iter(),
+            },
+            graph: self,
+        }
+    }
+
+    /// Return a reference to the weight of the edge with id `id` between
+    /// `a` and `b`, if it exists.
+    pub fn edge_weight(&self, a: N, b: N, id: usize) -> Option<&E> {
+        self.edges.get(&Self::edge_key(a, b, id))
+    }
+
+    /// Return a mutable reference to the weight of the edge with id `id`
+    /// between `a` and `b`, if it exists.
+    pub fn edge_weight_mut(&mut self, a: N, b: N, id: usize) -> Option<&mut E> {
+        self.edges.get_mut(&Self::edge_key(a, b, id))
+    }
+
+    /// Return an iterator over all edges of the graph with their edge id and
+    /// weight, in the order they were added (as long as no edges have been
+    /// removed).
+    ///
+    /// Iterator element type is `(N, N, usize, &E)`.
+    pub fn all_edges(&self) -> AllEdges<'_, N, E> {
+        AllEdges {
+            inner: self.edges.iter(),
+        }
+    }
+}
+
+impl<N, E, Ty> Default for MultiGraphMap<N, E, Ty>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+{
+    fn default() -> Self {
+        MultiGraphMap::with_capacity(0, 0)
+    }
+}
+
+/// Iterator over the edges connecting a pair of nodes.
+#[derive(Debug, Clone)]
+pub struct EdgesConnecting<'a, N, E: 'a, Ty>
+where
+    N: 'a + NodeTrait,
+    Ty: EdgeType,
+{
+    from: N,
+    to: N,
+    ids: Iter<'a, usize>,
+    graph: &'a MultiGraphMap<N, E, Ty>,
+}
+
+impl<'a, N, E, Ty> Iterator for EdgesConnecting<'a, N, E, Ty>
+where
+    N: 'a + NodeTrait,
+    E: 'a,
+    Ty: EdgeType,
+{
+    type Item = (usize, &'a E);
+    fn next(&mut self) -> Option<Self::Item> {
+        let (from, to) = (self.from, self.to);
+        let graph = self.graph;
+        self.ids
+            .next()
+            .map(|&id| (id, graph.edge_weight(from, to, id).unwrap()))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.ids.size_hint()
+    }
+}
+
+/// Iterator over all edges of a `MultiGraphMap`.
+#[derive(Debug, Clone)]
+pub struct AllEdges<'a, N, E: 'a> {
+    inner: IndexMapIter<'a, (N, N, usize), E>,
+}
+
+impl<'a, N, E> Iterator for AllEdges<'a, N, E>
+where
+    N: 'a + NodeTrait,
+{
+    type Item = (N, N, usize, &'a E);
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(&(a, b, id), w)| (a, b, id, w))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
@@ -9,6 +9,8 @@ use crate::{EdgeType, Graph};
 #[cfg(feature = "graphmap")]
 use crate::graphmap::{GraphMap, NodeTrait};
 use crate::visit::NodeIndexable;
+#[cfg(feature = "graphmap")]
+use std::hash::BuildHasher;
 
 /// Return a random float in the range [0, 1.)
 fn random_01<G: Gen>(g: &mut G) -> f64 {
@@ -176,16 +178,17 @@ where
 ///
 /// Requires crate features `"quickcheck"` and `"graphmap"`
 #[cfg(feature = "graphmap")]
-impl<N, E, Ty> Arbitrary for GraphMap<N, E, Ty>
+impl<N, E, Ty, S> Arbitrary for GraphMap<N, E, Ty, S>
 where
     N: NodeTrait + Arbitrary,
     E: Arbitrary,
     Ty: EdgeType + Clone + Send + 'static,
+    S: BuildHasher + Default + Clone + Send + 'static,
 {
     fn arbitrary<G: Gen>(g: &mut G) -> Self {
         let nodes = usize::arbitrary(g);
         if nodes == 0 {
-            return GraphMap::with_capacity(0, 0);
+            return GraphMap::with_capacity_default(0, 0);
         }
         let mut nodes = (0..nodes).map(|_| N::arbitrary(g)).collect::<Vec<_>>();
         nodes.sort();
@@ -194,7 +197,7 @@ where
         // use X² for edge probability (bias towards lower)
         let edge_prob = random_01(g) * random_01(g);
         let edges = ((nodes.len() as f64).powi(2) * edge_prob) as usize;
-        let mut gr = GraphMap::with_capacity(nodes.len(), edges);
+        let mut gr = GraphMap::with_capacity_default(nodes.len(), edges);
         for &node in &nodes {
             gr.add_node(node);
         }
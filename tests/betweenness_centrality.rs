@@ -0,0 +1,47 @@
+#![cfg(feature = "graphmap")]
+
+use petgraph::algo::edge_betweenness_centrality;
+use petgraph::prelude::*;
+
+#[test]
+fn edge_betweenness_barbell() {
+    // two triangles joined by the bridge 2 - 3
+    let graph =
+        UnGraphMap::<_, ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3)]);
+    let scores = edge_betweenness_centrality(&graph);
+    assert_eq!(scores.len(), graph.edge_count());
+    for (a, b, _) in graph.all_edges() {
+        assert!(scores.contains_key(&(a, b)));
+    }
+
+    // every path between the two halves crosses the bridge
+    assert_eq!(scores[&(2, 3)], 9.);
+    let (&max_edge, _) = scores
+        .iter()
+        .max_by(|x, y| x.1.partial_cmp(y.1).unwrap())
+        .unwrap();
+    assert_eq!(max_edge, (2, 3));
+    assert_eq!(scores[&(0, 1)], 1.);
+    assert_eq!(scores[&(1, 2)], 4.);
+}
+
+#[test]
+fn edge_betweenness_directed() {
+    // 0 -> 1 -> 2, and a shortcut 0 -> 2
+    let graph = DiGraphMap::<_, ()>::from_edges(&[(0, 1), (1, 2), (0, 2), (2, 2)]);
+    let scores = edge_betweenness_centrality(&graph);
+    assert_eq!(scores.len(), 3);
+    assert_eq!(scores[&(0, 1)], 1.);
+    assert_eq!(scores[&(1, 2)], 1.);
+    assert_eq!(scores[&(0, 2)], 1.);
+}
+
+#[test]
+fn edge_betweenness_split_paths() {
+    // a square: the two shortest paths between opposite corners share the load
+    let graph = UnGraphMap::<_, ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0)]);
+    let scores = edge_betweenness_centrality(&graph);
+    for score in scores.values() {
+        assert_eq!(*score, 2.);
+    }
+}
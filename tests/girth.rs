@@ -0,0 +1,54 @@
+#![cfg(feature = "graphmap")]
+
+use petgraph::algo::girth;
+use petgraph::prelude::*;
+
+#[test]
+fn girth_triangle_and_longer_cycle() {
+    // a triangle and a pentagon joined by a path
+    let graph = UnGraphMap::<u32, ()>::from_edges(&[
+        (0, 1),
+        (1, 2),
+        (2, 0),
+        (2, 3),
+        (3, 4),
+        (4, 5),
+        (5, 6),
+        (6, 7),
+        (7, 3),
+    ]);
+    assert_eq!(girth(&graph), Some(3));
+
+    let mut graph = graph;
+    graph.remove_edge(2, 0);
+    assert_eq!(girth(&graph), Some(5));
+}
+
+#[test]
+fn girth_acyclic() {
+    let graph = UnGraphMap::<u32, ()>::from_edges(&[(0, 1), (1, 2), (1, 3), (3, 4)]);
+    assert_eq!(girth(&graph), None);
+
+    let empty = UnGraphMap::<u32, ()>::new();
+    assert_eq!(girth(&empty), None);
+}
+
+#[test]
+fn girth_self_loop() {
+    let graph = UnGraphMap::<u32, ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (2, 2)]);
+    assert_eq!(girth(&graph), Some(1));
+}
+
+#[test]
+fn girth_directed() {
+    // 0 -> 1 -> 2 -> 0 is a directed cycle, 2 -> 3 -> 2 a shorter one
+    let mut graph = DiGraphMap::<u32, ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (2, 3), (3, 2)]);
+    assert_eq!(girth(&graph), Some(2));
+
+    graph.remove_edge(3, 2);
+    assert_eq!(girth(&graph), Some(3));
+
+    // edges forming an undirected triangle but no directed cycle
+    let dag = DiGraphMap::<u32, ()>::from_edges(&[(0, 1), (1, 2), (0, 2)]);
+    assert_eq!(girth(&dag), None);
+}
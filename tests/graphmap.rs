@@ -151,7 +151,8 @@ fn remove_directed() {
 
 #[test]
 fn dfs() {
-    let mut gr = UnGraphMap::default();
+    // `default` is generic over the hasher, see RELEASES.rst
+    let mut gr: UnGraphMap<_, _> = UnGraphMap::default();
     let h = gr.add_node("H");
     let i = gr.add_node("I");
     let j = gr.add_node("J");
@@ -413,3 +414,1634 @@ fn test_parallel_iterator() {
     let parallel_sum: u32 = gr.par_nodes().sum();
     assert_eq!(serial_sum, parallel_sum);
 }
+
+#[test]
+fn contract_node_with_shortcuts() {
+    // 0 - 1 - 2 - 3
+    let mut gr = UnGraphMap::<u32, u32>::from_edges(&[(0, 1, 2), (1, 2, 3), (2, 3, 4)]);
+    let before = dijkstra(&gr, 0, None, |e| *e.weight());
+
+    let shortcuts = gr.contract_node_with_shortcuts(1, |w| *w, |a, b| a + b);
+    assert_eq!(shortcuts, vec![(0, 2, 5)]);
+    assert!(!gr.contains_node(1));
+    assert_eq!(gr.edge_weight(0, 2), Some(&5));
+
+    let after = dijkstra(&gr, 0, None, |e| *e.weight());
+    assert_eq!(after[&3], before[&3]);
+    assert_eq!(after[&2], before[&2]);
+
+    // a witness path 0 - 3 - 2 shorter than 0 - 1 - 2 makes the shortcut unnecessary
+    let mut gr = UnGraphMap::<u32, u32>::from_edges(&[(0, 1, 5), (1, 2, 5), (0, 3, 1), (3, 2, 1)]);
+    assert_eq!(
+        gr.contract_node_with_shortcuts(1, |w| *w, |a, b| a + b),
+        vec![]
+    );
+    assert!(!gr.contains_edge(0, 2));
+}
+
+#[test]
+fn contract_node_with_shortcuts_directed() {
+    let mut gr = DiGraphMap::<u32, u32>::from_edges(&[(0, 1, 1), (1, 2, 1), (2, 1, 1), (3, 1, 7)]);
+    let mut shortcuts = gr.contract_node_with_shortcuts(1, |w| *w, |a, b| a + b);
+    shortcuts.sort();
+    assert_eq!(shortcuts, vec![(0, 2, 2), (3, 2, 8)]);
+    assert!(!gr.contains_edge(2, 0));
+    assert_eq!(gr.edge_count(), 2);
+}
+
+#[test]
+fn contract_node_with_shortcuts_cost() {
+    #[derive(Clone, Debug, PartialEq)]
+    struct Road {
+        minutes: u32,
+        name: String,
+    }
+    let road = |minutes, name: &str| Road {
+        minutes,
+        name: name.to_string(),
+    };
+    let join = |a: &Road, b: &Road| Road {
+        minutes: a.minutes + b.minutes,
+        name: format!("{}/{}", a.name, b.name),
+    };
+
+    // 0 - 1 - 2, and a slower detour 0 - 3 - 2
+    let mut gr = UnGraphMap::<u32, Road>::new();
+    gr.add_edge(0, 1, road(2, "a"));
+    gr.add_edge(1, 2, road(3, "b"));
+    gr.add_edge(0, 3, road(4, "c"));
+    gr.add_edge(3, 2, road(4, "d"));
+
+    let shortcuts = gr.contract_node_with_shortcuts(1, |r| r.minutes, join);
+    assert_eq!(shortcuts, vec![(0, 2, road(5, "a/b"))]);
+    assert_eq!(gr.edge_weight(0, 2), Some(&road(5, "a/b")));
+
+    // contracting 3 is unnecessary, the witness 0 - 2 costs less
+    assert_eq!(
+        gr.contract_node_with_shortcuts(3, |r| r.minutes, join),
+        vec![]
+    );
+    assert_eq!(gr.edge_count(), 1);
+}
+
+#[test]
+fn incidence_matrix() {
+    use petgraph::visit::{EdgeIndexable, NodeIndexable};
+
+    let gr = DiGraphMap::<_, ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (0, 3), (3, 3)]);
+    let matrix = gr.incidence_matrix();
+    assert_eq!(matrix.len(), gr.node_count());
+    for row in &matrix {
+        assert_eq!(row.len(), gr.edge_count());
+    }
+    for (a, b, _) in gr.all_edges() {
+        let e = EdgeIndexable::to_index(&gr, (a, b));
+        let (ai, bi) = (
+            NodeIndexable::to_index(&gr, a),
+            NodeIndexable::to_index(&gr, b),
+        );
+        if a == b {
+            assert_eq!(matrix[ai][e], 0);
+        } else {
+            assert_eq!(matrix[ai][e], -1);
+            assert_eq!(matrix[bi][e], 1);
+        }
+        let column_sum: i8 = matrix.iter().map(|row| row[e]).sum();
+        assert_eq!(column_sum, 0);
+    }
+    assert_eq!(matrix[0], vec![-1, 0, 1, -1, 0]);
+
+    let gr = UnGraphMap::<_, ()>::from_edges(&[(0, 1), (1, 1)]);
+    assert_eq!(gr.incidence_matrix(), vec![vec![1, 0], vec![1, 2]]);
+}
+
+#[test]
+fn laplacian_matrix() {
+    let gr = UnGraphMap::<_, f64>::from_edges(&[(0, 1, 1.5), (1, 2, 2.), (2, 0, 0.5), (2, 3, 4.)]);
+    let laplacian = gr.laplacian_matrix();
+    assert_eq!(laplacian.len(), 4);
+    for row in &laplacian {
+        assert_eq!(row.len(), 4);
+        assert!(row.iter().sum::<f64>().abs() < 1e-12);
+    }
+    assert_eq!(laplacian[2], vec![-0.5, -2., 6.5, -4.]);
+    for (i, row) in laplacian.iter().enumerate() {
+        for (j, &entry) in row.iter().enumerate() {
+            assert_eq!(entry, laplacian[j][i]);
+        }
+    }
+
+    // directed edges are symmetrized
+    let gr = DiGraphMap::<_, u8>::from_edges(&[(0, 1, 1), (1, 0, 2), (1, 1, 7)]);
+    assert_eq!(gr.laplacian_matrix(), vec![vec![3., -3.], vec![-3., 3.]]);
+}
+
+#[test]
+fn all_simple_paths_length_bound() {
+    use petgraph::algo::all_simple_paths;
+
+    // exactly three simple paths from 0 to 3: of 1, 2 and 3 edges
+    let gr = DiGraphMap::<_, ()>::from_edges(&[(0, 3), (0, 1), (1, 3), (1, 2), (2, 3)]);
+    let paths_up_to = |max_len: usize| {
+        let mut paths: Vec<Vec<_>> = all_simple_paths(&gr, 0, 3, 0, Some(max_len - 1)).collect();
+        paths.sort();
+        paths
+    };
+
+    assert_eq!(paths_up_to(1), vec![vec![0, 3]]);
+    assert_eq!(paths_up_to(2), vec![vec![0, 1, 3], vec![0, 3]]);
+    assert_eq!(
+        paths_up_to(3),
+        vec![vec![0, 1, 2, 3], vec![0, 1, 3], vec![0, 3]]
+    );
+    assert_eq!(paths_up_to(10), paths_up_to(3));
+}
+
+#[test]
+fn k_hop_neighborhood() {
+    // 0 - 1 - 2 - 3 - 4 - 5 - 6
+    let gr = UnGraphMap::<_, ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 6)]);
+    let mut hood = gr.k_hop_neighborhood(3, 2, Outgoing, false);
+    hood.sort();
+    assert_eq!(hood, vec![1, 2, 4, 5]);
+
+    let mut hood = gr.k_hop_neighborhood(3, 2, Outgoing, true);
+    hood.sort();
+    assert_eq!(hood, vec![1, 2, 3, 4, 5]);
+
+    assert_eq!(gr.k_hop_neighborhood(3, 0, Outgoing, false), vec![]);
+    assert_eq!(gr.k_hop_neighborhood(7, 2, Outgoing, true), vec![]);
+
+    let gr = DiGraphMap::<_, ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 4)]);
+    assert_eq!(gr.k_hop_neighborhood(2, 2, Outgoing, false), vec![3, 4]);
+    assert_eq!(gr.k_hop_neighborhood(2, 2, Incoming, false), vec![1, 0]);
+}
+
+#[test]
+fn subdivide_edge() {
+    let mut gr = DiGraphMap::new();
+    gr.add_edge(0, 1, 10.);
+    gr.add_edge(1, 2, 4.);
+
+    assert!(gr.subdivide_edge(0, 1, 5, |w| (w * 0.25, w * 0.75)));
+    assert_eq!(gr.node_count(), 4);
+    assert_eq!(gr.edge_count(), 3);
+    assert!(!gr.contains_edge(0, 1));
+    assert_eq!(gr.edge_weight(0, 5), Some(&2.5));
+    assert_eq!(gr.edge_weight(5, 1), Some(&7.5));
+    assert!(!gr.contains_edge(5, 0));
+    assert_eq!(gr.neighbors(0).collect::<Vec<_>>(), vec![5]);
+    assert_eq!(
+        gr.neighbors_directed(1, Incoming).collect::<Vec<_>>(),
+        vec![5]
+    );
+
+    // missing edge leaves the graph untouched
+    assert!(!gr.subdivide_edge(2, 1, 6, |w| (w, w)));
+    assert!(!gr.contains_node(6));
+    assert_eq!(gr.edge_count(), 3);
+
+    let mut gr = UnGraphMap::new();
+    gr.add_edge("a", "b", 3);
+    assert!(gr.subdivide_edge("b", "a", "m", |w| (w, w + 1)));
+    assert_eq!(gr.edge_weight("m", "b"), Some(&3));
+    assert_eq!(gr.edge_weight("a", "m"), Some(&4));
+}
+
+#[test]
+fn subdivide_edge_rejects_conflicts() {
+    let mut gr = DiGraphMap::new();
+    gr.add_edge(0, 1, 10);
+    gr.add_edge(0, 2, 3);
+    gr.add_edge(3, 1, 4);
+
+    // `m` equal to an endpoint would create a self loop
+    assert!(!gr.subdivide_edge(0, 1, 0, |w| (w, w)));
+    assert!(!gr.subdivide_edge(0, 1, 1, |w| (w, w)));
+    assert!(!gr.contains_edge(0, 0));
+    assert!(!gr.contains_edge(1, 1));
+
+    // existing edges a → m and m → b are not overwritten
+    assert!(!gr.subdivide_edge(0, 1, 2, |w| (w, w)));
+    assert!(!gr.subdivide_edge(0, 1, 3, |w| (w, w)));
+    assert_eq!(gr.edge_weight(0, 1), Some(&10));
+    assert_eq!(gr.edge_weight(0, 2), Some(&3));
+    assert_eq!(gr.edge_weight(3, 1), Some(&4));
+    assert!(!gr.contains_edge(2, 1));
+    assert!(!gr.contains_edge(0, 3));
+    assert_eq!(gr.edge_count(), 3);
+
+    // in an undirected graph either orientation counts
+    let mut gr = UnGraphMap::new();
+    gr.add_edge("a", "b", 1);
+    gr.add_edge("m", "a", 2);
+    assert!(!gr.subdivide_edge("a", "b", "m", |w| (w, w)));
+    assert_eq!(gr.edge_weight("a", "m"), Some(&2));
+    assert_eq!(gr.edge_weight("a", "b"), Some(&1));
+}
+
+#[test]
+fn eulerian_predicates() {
+    // a square: circuit
+    let mut gr = UnGraphMap::<_, ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0)]);
+    gr.add_node(7);
+    assert!(gr.has_eulerian_circuit());
+    assert!(gr.has_eulerian_path());
+
+    // a self loop keeps degrees even
+    gr.add_edge(2, 2, ());
+    assert!(gr.has_eulerian_circuit());
+
+    // a diagonal makes 0 and 2 odd: only a path
+    gr.add_edge(0, 2, ());
+    assert!(!gr.has_eulerian_circuit());
+    assert!(gr.has_eulerian_path());
+
+    // a star with three leaves: neither
+    let star = UnGraphMap::<_, ()>::from_edges(&[(0, 1), (0, 2), (0, 3)]);
+    assert!(!star.has_eulerian_circuit());
+    assert!(!star.has_eulerian_path());
+
+    // two disjoint triangles: degrees are even, but not connected
+    let split = UnGraphMap::<_, ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)]);
+    assert!(!split.has_eulerian_circuit());
+    assert!(!split.has_eulerian_path());
+
+    assert!(UnGraphMap::<u8, ()>::new().has_eulerian_circuit());
+}
+
+#[test]
+fn eulerian_predicates_directed() {
+    let mut gr = DiGraphMap::<_, ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (1, 1)]);
+    assert!(gr.has_eulerian_circuit());
+    assert!(gr.has_eulerian_path());
+
+    gr.remove_edge(2, 0);
+    assert!(!gr.has_eulerian_circuit());
+    assert!(gr.has_eulerian_path());
+
+    gr.add_edge(3, 2, ());
+    assert!(!gr.has_eulerian_circuit());
+    assert!(!gr.has_eulerian_path());
+}
+
+#[test]
+fn cut_edges() {
+    // two triangles joined by two edges
+    let gr = UnGraphMap::<_, u32>::from_edges(&[
+        (0, 1, 1),
+        (1, 2, 1),
+        (2, 0, 1),
+        (3, 4, 1),
+        (4, 5, 1),
+        (5, 3, 1),
+        (2, 3, 10),
+        (4, 0, 20),
+        (5, 6, 100),
+    ]);
+    let a: HashSet<_> = [0, 1, 2].iter().cloned().collect();
+    let b: HashSet<_> = [3, 4, 5].iter().cloned().collect();
+    let (edges, weight) = gr.cut_edges(&a, &b);
+    assert_eq!(edges, vec![(2, 3, &10), (0, 4, &20)]);
+    assert_eq!(weight, 30);
+
+    let (edges, weight) = gr.cut_edges(&b, &a);
+    assert_eq!(edges.len(), 2);
+    assert_eq!(weight, 30);
+
+    let mut dg = DiGraphMap::<_, u32>::from_edges(&[(0, 1, 1), (1, 0, 2), (1, 2, 4)]);
+    dg.add_node(3);
+    let a: HashSet<_> = [0].iter().cloned().collect();
+    let b: HashSet<_> = [1, 3].iter().cloned().collect();
+    assert_eq!(dg.cut_edges(&a, &b), (vec![(0, 1, &1), (1, 0, &2)], 3));
+}
+
+#[test]
+fn weighted_degree() {
+    let mut gr = UnGraphMap::<_, u32>::from_edges(&[(0, 1, 2), (2, 0, 3), (0, 3, 7), (1, 2, 100)]);
+    assert_eq!(gr.weighted_degree(0), 12);
+    assert_eq!(gr.weighted_in_degree(0), 12);
+    assert_eq!(gr.weighted_out_degree(0), 12);
+    assert_eq!(gr.weighted_degree(3), 7);
+    assert_eq!(gr.weighted_degree(9), 0);
+
+    // a self loop counts twice, like in `degree`
+    gr.add_edge(0, 0, 1);
+    assert_eq!(gr.degree(0), 5);
+    assert_eq!(gr.weighted_degree(0), 14);
+    assert_eq!(gr.weighted_out_degree(0), 14);
+    assert_eq!(gr.weighted_in_degree(0), 14);
+
+    let dg = DiGraphMap::<_, f64>::from_edges(&[(0, 1, 0.5), (2, 0, 1.5), (0, 3, 2.), (0, 0, 4.)]);
+    assert_eq!(dg.weighted_out_degree(0), 6.5);
+    assert_eq!(dg.weighted_in_degree(0), 5.5);
+    assert_eq!(dg.weighted_degree(0), 12.);
+    assert_eq!(
+        dg.weighted_degree(0),
+        dg.weighted_out_degree(0) + dg.weighted_in_degree(0)
+    );
+    assert_eq!(dg.weighted_in_degree(1), 0.5);
+    assert_eq!(dg.weighted_out_degree(1), 0.);
+}
+
+#[test]
+fn relabel_consecutive() {
+    let mut gr = DiGraphMap::new();
+    gr.add_node(50);
+    gr.add_edge(30, 10, 'a');
+    gr.add_edge(10, 20, 'b');
+    gr.add_edge(20, 30, 'c');
+    gr.add_edge(20, 20, 'd');
+    let (relabeled, nodes) = gr.relabel_consecutive();
+    assert_eq!(nodes, vec![10, 20, 30, 50]);
+    assert_eq!(relabeled.nodes().collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+    assert_eq!(relabeled.edge_count(), gr.edge_count());
+    for (a, b, w) in relabeled.all_edges() {
+        assert_eq!(
+            gr.edge_weight(nodes[a as usize], nodes[b as usize]),
+            Some(w)
+        );
+    }
+
+    // the labels do not depend on insertion order
+    let mut rev = DiGraphMap::new();
+    for (a, b, &w) in gr.all_edges().collect::<Vec<_>>().into_iter().rev() {
+        rev.add_edge(a, b, w);
+    }
+    rev.add_node(50);
+    let (rev_relabeled, rev_nodes) = rev.relabel_consecutive();
+    assert_eq!(rev_nodes, nodes);
+    for (a, b, w) in relabeled.all_edges() {
+        assert_eq!(rev_relabeled.edge_weight(a, b), Some(w));
+    }
+}
+
+#[test]
+fn merge_nodes_by() {
+    // 12 and 13 normalize to the same entity
+    let mut gr = DiGraphMap::<u32, u32>::new();
+    gr.add_edge(12, 20, 1);
+    gr.add_edge(13, 20, 2);
+    gr.add_edge(30, 13, 4);
+    gr.add_edge(12, 13, 8);
+    gr.add_node(40);
+    let merged = gr.merge_nodes_by(|n| n / 10, |a, b| a + b);
+    assert_eq!(merged.nodes().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+    assert_eq!(merged.edge_count(), 3);
+    assert_eq!(merged.edge_weight(1, 2), Some(&3));
+    assert_eq!(merged.edge_weight(3, 1), Some(&4));
+    assert_eq!(merged.edge_weight(1, 1), Some(&8));
+    assert_eq!(merged.edge_weight(2, 1), None);
+
+    let un = UnGraphMap::<u32, u32>::from_edges(&[(12, 20, 1), (20, 13, 2)]);
+    let merged = un.merge_nodes_by(|n| n / 10, std::cmp::max);
+    assert_eq!(merged.edge_count(), 1);
+    assert_eq!(merged.edge_weight(2, 1), Some(&2));
+}
+
+#[test]
+fn weighted_triangle_count() {
+    let geometric_mean = |a: &f64, b: &f64, c: &f64| (a * b * c).cbrt();
+
+    let mut gr = UnGraphMap::<_, f64>::from_edges(&[(0, 1, 2.), (1, 2, 4.), (2, 0, 8.)]);
+    assert_eq!(gr.weighted_triangle_count(geometric_mean), 4.);
+
+    // a second triangle sharing the edge 1 - 2, and a self loop
+    gr.add_edge(1, 3, 1.);
+    gr.add_edge(3, 2, 2.);
+    gr.add_edge(3, 3, 100.);
+    assert_eq!(gr.weighted_triangle_count(geometric_mean), 6.);
+    assert_eq!(gr.weighted_triangle_count(|_, _, _| 1.), 2.);
+
+    let dg = DiGraphMap::<_, f64>::from_edges(&[(0, 1, 1.), (1, 0, 5.), (2, 1, 1.), (0, 2, 1.)]);
+    assert_eq!(dg.weighted_triangle_count(|a, b, c| a + b + c), 3.);
+
+    assert_eq!(
+        UnGraphMap::<u8, f64>::new().weighted_triangle_count(geometric_mean),
+        0.
+    );
+}
+
+#[test]
+fn split_into_components() {
+    let mut gr = DiGraphMap::new();
+    gr.add_edge("a", "b", 1);
+    gr.add_edge("c", "d", 2);
+    gr.add_edge("e", "b", 3);
+    gr.add_edge("d", "d", 4);
+    gr.add_node("f");
+    let components = gr.split_into_components();
+    assert_eq!(components.len(), 3);
+
+    let first = &components[0];
+    assert_eq!(first.nodes().collect::<Vec<_>>(), vec!["a", "b", "e"]);
+    assert_eq!(
+        first.all_edges().collect::<Vec<_>>(),
+        vec![("a", "b", &1), ("e", "b", &3)]
+    );
+    let second = &components[1];
+    assert_eq!(second.nodes().collect::<Vec<_>>(), vec!["c", "d"]);
+    assert_eq!(
+        second.all_edges().collect::<Vec<_>>(),
+        vec![("c", "d", &2), ("d", "d", &4)]
+    );
+    let third = &components[2];
+    assert_eq!(third.nodes().collect::<Vec<_>>(), vec!["f"]);
+    assert_eq!(third.edge_count(), 0);
+
+    assert!(DiGraphMap::<u8, ()>::new()
+        .split_into_components()
+        .is_empty());
+}
+
+#[test]
+fn edge_weight_or_insert_with() {
+    let mut gr = UnGraphMap::new();
+    gr.add_node(1);
+    let mut calls = 0;
+    *gr.edge_weight_or_insert_with(1, 2, || {
+        calls += 1;
+        10
+    }) += 1;
+    assert_eq!(gr.edge_weight(2, 1), Some(&11));
+
+    let weight = gr.edge_weight_or_insert_with(2, 1, || {
+        calls += 1;
+        0
+    });
+    assert_eq!(*weight, 11);
+    *weight = 12;
+    assert_eq!(calls, 1);
+    assert_eq!(gr.edge_weight(1, 2), Some(&12));
+    assert_eq!(gr.edge_count(), 1);
+    assert_eq!(gr.neighbors(1).collect::<Vec<_>>(), vec![2]);
+    assert_eq!(gr.neighbors(2).collect::<Vec<_>>(), vec![1]);
+}
+
+#[test]
+fn retain_edges() {
+    let mut gr = DiGraphMap::<_, i32>::new();
+    gr.add_edge(1, 2, 1);
+    gr.add_edge(2, 1, -1);
+    gr.add_edge(2, 3, -2);
+    gr.add_edge(3, 3, -3);
+    gr.add_edge(1, 1, 4);
+    gr.add_edge(3, 1, 5);
+    gr.retain_edges(|_, _, w| *w >= 0);
+    assert_eq!(
+        gr.all_edges().collect::<Vec<_>>(),
+        vec![(1, 2, &1), (1, 1, &4), (3, 1, &5)]
+    );
+    assert_eq!(gr.node_count(), 3);
+    assert_eq!(
+        gr.neighbors(1).collect::<HashSet<_>>(),
+        [1, 2].iter().cloned().collect()
+    );
+    assert_eq!(gr.neighbors(2).count(), 0);
+    assert_eq!(gr.neighbors(3).collect::<Vec<_>>(), vec![1]);
+    assert_eq!(
+        gr.neighbors_directed(1, Incoming).collect::<HashSet<_>>(),
+        [1, 3].iter().cloned().collect()
+    );
+    assert_eq!(
+        gr.neighbors_directed(2, Incoming).collect::<Vec<_>>(),
+        vec![1]
+    );
+    assert_eq!(gr.neighbors_directed(3, Incoming).count(), 0);
+
+    let mut un = UnGraphMap::<_, i32>::from_edges(&[(1, 2, 1), (2, 3, 2), (3, 3, 3)]);
+    un.retain_edges(|a, b, w| {
+        *w *= 10;
+        a != b && *w < 15
+    });
+    assert_eq!(un.all_edges().collect::<Vec<_>>(), vec![(1, 2, &10)]);
+    assert_eq!(un.neighbors(2).collect::<Vec<_>>(), vec![1]);
+    assert_eq!(un.neighbors(3).count(), 0);
+}
+
+#[test]
+fn retain_nodes() {
+    let mut gr = UnGraphMap::<_, char>::new();
+    gr.add_edge(1, 2, 'a');
+    gr.add_edge(2, 3, 'b');
+    gr.add_edge(2, 4, 'c');
+    gr.add_edge(4, 6, 'd');
+    gr.add_edge(5, 5, 'e');
+    gr.add_edge(6, 6, 'f');
+    gr.add_node(8);
+    gr.retain_nodes(|n| n % 2 == 0);
+    assert_eq!(gr.nodes().collect::<Vec<_>>(), vec![2, 4, 6, 8]);
+    assert_eq!(
+        gr.all_edges().collect::<Vec<_>>(),
+        vec![(2, 4, &'c'), (4, 6, &'d'), (6, 6, &'f')]
+    );
+    assert_eq!(gr.neighbors(2).collect::<Vec<_>>(), vec![4]);
+    assert_eq!(gr.neighbors(4).collect::<Vec<_>>(), vec![2, 6]);
+    assert_eq!(gr.neighbors(6).collect::<Vec<_>>(), vec![4, 6]);
+    assert_eq!(gr.neighbors(8).count(), 0);
+
+    let mut dg = DiGraphMap::<_, ()>::from_edges(&[(1, 2), (2, 1), (3, 1)]);
+    dg.retain_nodes(|n| n != 2);
+    assert_eq!(dg.all_edges().collect::<Vec<_>>(), vec![(3, 1, &())]);
+    assert_eq!(
+        dg.neighbors_directed(1, Incoming).collect::<Vec<_>>(),
+        vec![3]
+    );
+    assert_eq!(dg.neighbors(1).count(), 0);
+}
+
+#[test]
+fn map() {
+    let mut gr = UnGraphMap::<u32, f32>::new();
+    gr.add_edge(1, 2, 1.5);
+    gr.add_edge(3, 2, 2.5);
+    gr.add_edge(3, 3, -1.);
+    gr.add_node(7);
+    let mapped = gr.map(|n| n * 2, |a, b, w| format!("{}-{}:{}", a, b, w));
+    assert_eq!(mapped.nodes().collect::<Vec<_>>(), vec![2, 4, 6, 14]);
+    assert_eq!(mapped.edge_count(), 3);
+    assert_eq!(mapped.edge_weight(2, 4), Some(&"1-2:1.5".to_string()));
+    assert_eq!(mapped.edge_weight(4, 6), Some(&"2-3:2.5".to_string()));
+    assert_eq!(mapped.edge_weight(6, 6), Some(&"3-3:-1".to_string()));
+
+    // colliding nodes are merged
+    let dg = DiGraphMap::<u32, u32>::from_edges(&[(1, 3, 1), (2, 3, 2), (3, 4, 3)]);
+    let merged = dg.map(|n| n / 2, |_, _, w| w);
+    assert_eq!(merged.nodes().collect::<Vec<_>>(), vec![0, 1, 2]);
+    assert_eq!(merged.edge_weight(0, 1), Some(&1));
+    assert_eq!(merged.edge_weight(1, 1), Some(&2));
+    assert_eq!(merged.edge_weight(1, 2), Some(&3));
+}
+
+#[test]
+fn filter_map() {
+    let mut gr = UnGraphMap::<u32, i32>::new();
+    gr.add_edge(1, 2, 5);
+    gr.add_edge(2, 3, -5);
+    gr.add_edge(3, 4, 6);
+    gr.add_edge(4, 1, 7);
+    gr.add_edge(4, 5, 8);
+    gr.add_edge(5, 5, 9);
+    let keep: HashSet<u32> = [1, 3, 4, 9].iter().cloned().collect();
+    let mut edge_calls = 0;
+    let filtered = gr.filter_map(
+        |n| {
+            if keep.contains(&n) {
+                Some(n * 10)
+            } else {
+                None
+            }
+        },
+        |_, _, w| {
+            edge_calls += 1;
+            if w > 0 {
+                Some(w as u32)
+            } else {
+                None
+            }
+        },
+    );
+    assert_eq!(edge_calls, 2);
+    assert_eq!(filtered.nodes().collect::<Vec<_>>(), vec![10, 30, 40]);
+    assert_eq!(
+        filtered.all_edges().collect::<Vec<_>>(),
+        vec![(30, 40, &6), (10, 40, &7)]
+    );
+    assert_eq!(filtered.neighbors(40).collect::<Vec<_>>(), vec![30, 10]);
+}
+
+#[test]
+fn degree() {
+    let mut gr = DiGraphMap::<_, ()>::new();
+    gr.add_edge('a', 'b', ());
+    gr.add_edge('a', 'c', ());
+    gr.add_edge('c', 'a', ());
+    gr.add_edge('a', 'a', ());
+    gr.add_node('d');
+    assert_eq!(gr.out_degree('a'), 3);
+    assert_eq!(gr.in_degree('a'), 2);
+    assert_eq!(gr.degree('a'), 5);
+    assert_eq!(gr.out_degree('b'), 0);
+    assert_eq!(gr.in_degree('b'), 1);
+    assert_eq!(gr.degree('d'), 0);
+    assert_eq!(gr.degree('z'), 0);
+    assert_eq!(
+        gr.out_degree('a'),
+        gr.neighbors_directed('a', Outgoing).count()
+    );
+    assert_eq!(
+        gr.in_degree('a'),
+        gr.neighbors_directed('a', Incoming).count()
+    );
+
+    let un = UnGraphMap::<_, ()>::from_edges(&[('a', 'b'), ('c', 'a'), ('a', 'a')]);
+    assert_eq!(un.degree('a'), 4);
+    assert_eq!(un.in_degree('a'), 4);
+    assert_eq!(un.out_degree('a'), 4);
+    assert_eq!(un.degree('b'), 1);
+}
+
+#[test]
+fn reverse() {
+    let mut gr = DiGraphMap::<_, u8>::new();
+    gr.add_edge(1, 2, 12);
+    gr.add_edge(3, 1, 31);
+    gr.add_edge(1, 1, 11);
+    gr.add_edge(2, 1, 21);
+    gr.add_node(4);
+    let incoming: HashSet<_> = gr.neighbors_directed(1, Incoming).collect();
+    let outgoing: HashSet<_> = gr.neighbors_directed(1, Outgoing).collect();
+
+    gr.reverse();
+    assert_eq!(
+        gr.neighbors_directed(1, Outgoing).collect::<HashSet<_>>(),
+        incoming
+    );
+    assert_eq!(
+        gr.neighbors_directed(1, Incoming).collect::<HashSet<_>>(),
+        outgoing
+    );
+    assert_eq!(gr.edge_weight(2, 1), Some(&12));
+    assert_eq!(gr.edge_weight(1, 3), Some(&31));
+    assert_eq!(gr.edge_weight(1, 1), Some(&11));
+    assert_eq!(gr.edge_weight(1, 2), Some(&21));
+    assert_eq!(gr.edge_weight(3, 1), None);
+    assert_eq!(gr.neighbors(3).count(), 0);
+    assert_eq!(
+        gr.neighbors_directed(3, Incoming).collect::<Vec<_>>(),
+        vec![1]
+    );
+    assert_eq!(gr.node_count(), 4);
+
+    let mut un = UnGraphMap::<_, ()>::from_edges(&[(1, 2)]);
+    un.reverse();
+    assert!(un.contains_edge(1, 2));
+    assert_eq!(un.neighbors(1).collect::<Vec<_>>(), vec![2]);
+}
+
+#[test]
+fn clear_edges() {
+    let mut gr = DiGraphMap::<_, ()>::from_edges(&[(3, 1), (1, 2), (2, 2)]);
+    gr.add_node(0);
+    gr.clear_edges();
+    assert_eq!(gr.node_count(), 4);
+    assert_eq!(gr.edge_count(), 0);
+    assert_eq!(gr.nodes().collect::<Vec<_>>(), vec![3, 1, 2, 0]);
+    assert!(gr
+        .nodes()
+        .all(|n| gr.neighbors_directed(n, Incoming).count() == 0));
+    assert!(gr.nodes().all(|n| gr.neighbors(n).count() == 0));
+
+    gr.add_edge(1, 3, ());
+    assert_eq!(gr.neighbors(1).collect::<Vec<_>>(), vec![3]);
+    assert_eq!(gr.edge_count(), 1);
+}
+
+#[test]
+fn shrink_to_fit() {
+    let mut gr = UnGraphMap::<u32, ()>::new();
+    for i in 0..1000 {
+        gr.add_edge(i % 10, i, ());
+    }
+    let (nodes, edges) = gr.capacity();
+    gr.retain_edges(|a, _, _| a == 0);
+    gr.retain_nodes(|n| n < 100);
+    gr.shrink_to_fit();
+    let (shrunk_nodes, shrunk_edges) = gr.capacity();
+    assert!(shrunk_nodes < nodes && shrunk_nodes >= gr.node_count());
+    assert!(shrunk_edges < edges && shrunk_edges >= gr.edge_count());
+    assert_eq!(gr.neighbors(0).count(), 10);
+}
+
+#[test]
+fn reserve() {
+    let mut gr = DiGraphMap::<u32, ()>::from_edges(&[(0, 1)]);
+    gr.reserve_nodes(500);
+    gr.reserve_edges(1000);
+    let (nodes, edges) = gr.capacity();
+    assert!(nodes >= 502);
+    assert!(edges >= 1001);
+    for i in 0..500 {
+        gr.add_edge(i, i + 1, ());
+        gr.add_edge(i + 1, i, ());
+    }
+    assert_eq!(gr.capacity(), (nodes, edges));
+}
+
+#[test]
+fn into_nodes_edges() {
+    let mut gr = DiGraphMap::<_, i32>::new();
+    gr.add_node("z");
+    gr.add_edge("b", "a", 1);
+    gr.add_edge("a", "b", 2);
+    gr.add_edge("c", "c", 3);
+    let (nodes, edges) = gr.clone().into_nodes_edges();
+    assert_eq!(nodes, vec!["z", "b", "a", "c"]);
+    assert_eq!(edges, vec![("b", "a", 1), ("a", "b", 2), ("c", "c", 3)]);
+
+    let mut rebuilt = DiGraphMap::new();
+    for &n in &nodes {
+        rebuilt.add_node(n);
+    }
+    rebuilt.extend(edges);
+    assert_eq!(rebuilt.nodes().collect::<Vec<_>>(), nodes);
+    assert_eq!(
+        rebuilt.all_edges().collect::<Vec<_>>(),
+        gr.all_edges().collect::<Vec<_>>()
+    );
+
+    let un = UnGraphMap::<_, ()>::from_edges(&[(3, 1), (2, 1)]);
+    let (_, edges) = un.into_nodes_edges();
+    assert_eq!(edges, vec![(1, 3, ()), (1, 2, ())]);
+    let rebuilt = UnGraphMap::<_, ()>::from_edges(edges);
+    assert!(rebuilt.contains_edge(3, 1));
+}
+
+#[test]
+fn union() {
+    // two triangles sharing the edge 2 - 3
+    let mut gr = UnGraphMap::<_, u32>::from_edges(&[(1, 2, 1), (2, 3, 2), (3, 1, 3)]);
+    let other = UnGraphMap::<_, u32>::from_edges(&[(2, 3, 20), (3, 4, 30), (4, 2, 40), (4, 4, 1)]);
+    gr.union(other, |w, other| *w += other);
+    assert_eq!(gr.nodes().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+    assert_eq!(gr.edge_count(), 6);
+    assert_eq!(gr.edge_weight(3, 2), Some(&22));
+    assert_eq!(gr.edge_weight(1, 3), Some(&3));
+    assert_eq!(gr.edge_weight(2, 4), Some(&40));
+    assert_eq!(gr.edge_weight(4, 4), Some(&1));
+    assert_eq!(gr.neighbors(2).count(), 3);
+
+    let mut dg = DiGraphMap::<_, &str>::from_edges(&[(1, 2, "a"), (2, 2, "b")]);
+    let other = DiGraphMap::<_, &str>::from_edges(&[(2, 1, "c"), (2, 2, "d")]);
+    dg.union(other, |w, other| *w = other);
+    assert_eq!(dg.edge_weight(1, 2), Some(&"a"));
+    assert_eq!(dg.edge_weight(2, 1), Some(&"c"));
+    assert_eq!(dg.edge_weight(2, 2), Some(&"d"));
+    assert_eq!(dg.neighbors_directed(2, Incoming).count(), 2);
+}
+
+#[test]
+fn subgraph() {
+    let gr = UnGraphMap::<_, u8>::from_edges(&[(1, 2, 12), (2, 3, 23), (3, 1, 31), (1, 1, 11)]);
+    let sub = gr.subgraph(vec![3, 1, 7]);
+    assert_eq!(sub.nodes().collect::<Vec<_>>(), vec![3, 1]);
+    assert_eq!(
+        sub.all_edges().collect::<Vec<_>>(),
+        vec![(1, 3, &31), (1, 1, &11)]
+    );
+    assert_eq!(sub.neighbors(3).collect::<Vec<_>>(), vec![1]);
+
+    let dg = DiGraphMap::<_, ()>::from_edges(&[(1, 2), (2, 1), (2, 3)]);
+    let sub = dg.subgraph(dg.nodes().filter(|&n| n != 3));
+    assert_eq!(sub.edge_count(), 2);
+    assert!(sub.contains_edge(2, 1));
+    assert_eq!(dg.subgraph(Vec::new()).node_count(), 0);
+}
+
+#[test]
+fn contract_nodes() {
+    let mut gr = UnGraphMap::<_, u32>::new();
+    gr.add_edge(1, 2, 1);
+    gr.add_edge(2, 3, 2);
+    gr.add_edge(3, 1, 4);
+    gr.add_edge(2, 4, 8);
+    gr.add_edge(2, 2, 16);
+    let mut merged = gr.clone();
+    assert!(merged.contract_nodes(1, 2, true, |w, other| *w += other));
+    assert_eq!(merged.nodes().collect::<Vec<_>>(), vec![1, 4, 3]);
+    assert_eq!(merged.edge_count(), 3);
+    assert_eq!(merged.edge_weight(1, 3), Some(&6));
+    assert_eq!(merged.edge_weight(1, 4), Some(&8));
+    assert_eq!(merged.edge_weight(1, 1), Some(&17));
+    assert_eq!(merged.degree(1), 4);
+
+    assert!(gr.contract_nodes(1, 2, false, |w, other| *w += other));
+    assert_eq!(gr.edge_count(), 2);
+    assert!(!gr.contains_edge(1, 1));
+    assert!(!gr.contract_nodes(1, 2, false, |w, other| *w += other));
+    assert!(!gr.contract_nodes(1, 1, false, |w, other| *w += other));
+
+    let mut dg = DiGraphMap::<_, u32>::new();
+    dg.add_edge('a', 'b', 1);
+    dg.add_edge('b', 'c', 2);
+    dg.add_edge('c', 'b', 4);
+    dg.add_edge('a', 'c', 8);
+    dg.add_edge('c', 'a', 16);
+    assert!(dg.contract_nodes('a', 'b', false, |w, other| *w += other));
+    assert_eq!(dg.edge_count(), 2);
+    assert_eq!(dg.edge_weight('a', 'c'), Some(&10));
+    assert_eq!(dg.edge_weight('c', 'a'), Some(&20));
+    assert_eq!(
+        dg.neighbors_directed('a', Incoming).collect::<Vec<_>>(),
+        vec!['c']
+    );
+}
+
+#[test]
+fn complement() {
+    // a path with 6 nodes
+    let path = UnGraphMap::<_, ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 4), (4, 5)]);
+    let n = path.node_count();
+    let comp = path.complement(false);
+    assert_eq!(comp.node_count(), n);
+    assert_eq!(comp.edge_count(), n * (n - 1) / 2 - path.edge_count());
+    for (a, b, _) in comp.all_edges() {
+        assert!(a != b && !path.contains_edge(a, b));
+    }
+    assert_eq!(path.complement(true).edge_count(), comp.edge_count() + n);
+
+    let mut dg = DiGraphMap::<_, ()>::from_edges(&[(0, 1), (1, 2), (2, 2)]);
+    dg.add_node(3);
+    let comp = dg.complement(true);
+    assert_eq!(comp.edge_count(), 4 * 4 - 3);
+    assert!(comp.contains_edge(1, 0));
+    assert!(comp.contains_edge(0, 0));
+    assert!(!comp.contains_edge(2, 2));
+    assert_eq!(dg.complement(false).edge_count(), 4 * 3 - 2);
+}
+
+#[test]
+fn node_index() {
+    use petgraph::visit::NodeIndexable;
+
+    let mut gr = DiGraphMap::<_, ()>::from_edges(&[(5, 3), (3, 9)]);
+    assert_eq!(gr.node_index(5), Some(0));
+    assert_eq!(gr.node_index(9), Some(2));
+    assert_eq!(gr.node_index(4), None);
+    assert_eq!(gr.to_index(3), 1);
+    assert_eq!(gr.from_index(gr.node_index(9).unwrap()), 9);
+
+    gr.remove_node(5);
+    assert_eq!(gr.node_index(5), None);
+    assert_eq!(gr.node_index(9), Some(0));
+}
+
+#[test]
+fn custom_hasher() {
+    use std::collections::hash_map::{DefaultHasher, RandomState};
+    use std::hash::BuildHasherDefault;
+
+    let mut g: GraphMap<_, _, Directed, RandomState> = GraphMap::with_hasher(RandomState::new());
+    g.add_edge(1, 2, 1.);
+    g.add_edge(2, 3, 2.);
+    g.add_edge(3, 1, 3.);
+    assert_eq!(g.node_count(), 3);
+    assert_eq!(g.edge_weight(2, 3), Some(&2.));
+    assert!(!g.contains_edge(3, 2));
+    assert!(g.remove_node(2));
+    assert_eq!(g.edge_count(), 1);
+
+    type Fixed = BuildHasherDefault<DefaultHasher>;
+    let mut g = UnGraphMap::<_, _, Fixed>::with_capacity_and_hasher(4, 4, Fixed::default());
+    g.add_edge("a", "b", 1);
+    g.add_edge("b", "c", 2);
+    let scores = dijkstra(&g, "a", None, |e| *e.weight());
+    assert_eq!(scores[&"c"], 3);
+    assert_eq!(g.neighbors("b").collect::<HashSet<_>>().len(), 2);
+    let sub = g.subgraph(vec!["a", "b"]);
+    assert_eq!(sub.edge_count(), 1);
+
+    let mut g: GraphMap<_, _, Directed, Fixed> = GraphMap::default();
+    g.add_edge(1, 2, ());
+    assert_eq!(g.edge_count(), 1);
+
+    let g = DiGraphMap::<_, (), Fixed>::from_edges(&[(1, 2), (2, 3)]);
+    assert!(g.contains_edge(2, 3));
+    let h: DiGraphMap<_, (), Fixed> = g.all_edges().map(|(a, b, _)| (b, a)).collect();
+    assert!(h.contains_edge(3, 2));
+    assert_eq!(h.node_count(), 3);
+}
+
+#[test]
+fn self_loops() {
+    let mut g = UnGraphMap::new();
+    g.add_edge(1, 1, 10);
+    g.add_edge(1, 2, 12);
+    g.add_edge(3, 3, 30);
+    g.add_node(4);
+    let loops: Vec<_> = g.self_loops().collect();
+    assert_eq!(loops, vec![(1, &10), (3, &30)]);
+
+    g.remove_edge(1, 1);
+    assert_eq!(g.self_loops().collect::<Vec<_>>(), vec![(3, &30)]);
+}
+
+#[test]
+fn is_empty() {
+    let mut g = DiGraphMap::new();
+    assert!(g.is_empty());
+    assert!(!g.has_edges());
+    g.add_edge(1, 2, ());
+    assert!(!g.is_empty());
+    assert!(g.has_edges());
+    g.clear_edges();
+    assert!(!g.is_empty());
+    assert!(!g.has_edges());
+    g.clear();
+    assert!(g.is_empty());
+}
+
+#[test]
+fn to_undirected() {
+    let g = DiGraphMap::<_, u32>::from_edges(&[(1, 2, 1), (2, 1, 2), (2, 3, 4), (3, 3, 8)]);
+    let u = g.to_undirected(|existing, other| *existing = (*existing).max(other));
+    assert_eq!(u.node_count(), 3);
+    assert_eq!(u.edge_count(), 3);
+    assert_eq!(u.edge_weight(1, 2), Some(&2));
+    assert_eq!(u.edge_weight(3, 2), Some(&4));
+    assert_eq!(u.edge_weight(3, 3), Some(&8));
+
+    // an undirected graph is copied unchanged
+    let again = u.to_undirected(|_, _| panic!("no anti-parallel edges"));
+    assert_eq!(
+        again.all_edges().collect::<Vec<_>>(),
+        u.all_edges().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn to_directed() {
+    let mut g = UnGraphMap::<_, u32>::from_edges(&[(1, 2, 1), (3, 2, 2), (3, 3, 4)]);
+    g.add_node(4);
+    let d = g.to_directed();
+    assert_eq!(d.node_count(), 4);
+    assert_eq!(d.edge_count(), 5);
+    assert_eq!(d.edge_weight(1, 2), Some(&1));
+    assert_eq!(d.edge_weight(2, 1), Some(&1));
+    assert_eq!(d.edge_weight(2, 3), Some(&2));
+    assert_eq!(d.edge_weight(3, 2), Some(&2));
+    assert_eq!(d.edge_weight(3, 3), Some(&4));
+
+    // and back again
+    let u = d.to_undirected(|existing, other| assert_eq!(*existing, other));
+    assert_eq!(u.edge_count(), g.edge_count());
+    assert_eq!(u.edge_weight(2, 3), Some(&2));
+}
+
+#[test]
+fn remove_node_stable() {
+    use petgraph::visit::NodeIndexable;
+
+    let mut g = DiGraphMap::<_, ()>::from_edges(&[(1, 2), (3, 1), (4, 5), (5, 3), (2, 2)]);
+    g.add_node(6);
+    let before: Vec<_> = g.nodes().map(|n| (n, g.to_index(n))).collect();
+    assert!(g.remove_node_stable(2));
+    assert!(!g.remove_node_stable(2));
+
+    assert_eq!(g.nodes().collect::<Vec<_>>(), vec![1, 3, 4, 5, 6]);
+    for (n, index) in before {
+        match n {
+            1 => assert_eq!(g.to_index(n), index),
+            2 => assert!(!g.contains_node(n)),
+            _ => assert_eq!(g.to_index(n), index - 1),
+        }
+    }
+    assert_eq!(
+        g.all_edges().map(|(a, b, _)| (a, b)).collect::<Vec<_>>(),
+        vec![(3, 1), (4, 5), (5, 3)]
+    );
+    assert_eq!(g.neighbors(1).count(), 0);
+}
+
+#[test]
+fn edge_weights() {
+    let mut g = DiGraphMap::<_, u32>::from_edges(&[(1, 2, 1), (2, 3, 2), (3, 1, 4), (3, 3, 8)]);
+    assert_eq!(g.edge_weights().sum::<u32>(), 15);
+    assert_eq!(g.edge_weights().len(), g.edge_count());
+
+    for weight in g.edge_weights_mut() {
+        *weight *= 10;
+    }
+    assert_eq!(g.edge_weights().sum::<u32>(), 150);
+    assert_eq!(g.edge_weight(3, 1), Some(&40));
+}
+
+#[test]
+fn neighbor_count() {
+    fn check<Ty: petgraph::EdgeType>(g: &GraphMap<u32, (), Ty>) {
+        for n in g.nodes().chain(Some(100)) {
+            assert_eq!(g.neighbor_count(n), g.neighbors(n).count());
+            for &dir in &[Outgoing, Incoming] {
+                assert_eq!(
+                    g.neighbor_count_directed(n, dir),
+                    g.neighbors_directed(n, dir).count()
+                );
+            }
+        }
+    }
+    let edges = [(0, 1), (1, 2), (2, 0), (0, 3), (3, 3), (4, 0)];
+    let mut g = DiGraphMap::from_edges(&edges);
+    g.add_node(5);
+    check(&g);
+    let mut g = UnGraphMap::from_edges(&edges);
+    g.add_node(5);
+    check(&g);
+}
+
+#[test]
+fn edges_directed_mut() {
+    let mut g =
+        DiGraphMap::<_, i32>::from_edges(&[(0, 1, 1), (2, 0, 2), (0, 3, 3), (1, 3, 4), (0, 0, 5)]);
+    let mut outgoing: Vec<_> = g
+        .edges_directed_mut(0, Outgoing)
+        .map(|(a, b, weight)| {
+            *weight = -*weight;
+            (a, b)
+        })
+        .collect();
+    outgoing.sort();
+    assert_eq!(outgoing, vec![(0, 0), (0, 1), (0, 3)]);
+    assert_eq!(
+        g.all_edges().map(|(_, _, &w)| w).collect::<Vec<_>>(),
+        vec![-1, 2, -3, 4, -5]
+    );
+    assert_eq!(g.edges_directed_mut(0, Incoming).count(), 2);
+    assert_eq!(g.edges_directed_mut(9, Incoming).count(), 0);
+
+    // undirected edges are produced with `a` as source, like `edges_directed`
+    let mut g = UnGraphMap::<_, i32>::from_edges(&[(1, 0, 1), (0, 2, 2)]);
+    let mut incident: Vec<_> = g
+        .edges_directed_mut(0, Outgoing)
+        .map(|(a, b, _)| (a, b))
+        .collect();
+    incident.sort();
+    assert_eq!(incident, vec![(0, 1), (0, 2)]);
+}
+
+#[test]
+fn edge_entry() {
+    // weighted co-occurrence of letters within words
+    let words = ["abc", "bcd", "cab", "dd"];
+    let mut g = UnGraphMap::<char, u32>::new();
+    for word in &words {
+        let letters: Vec<_> = word.chars().collect();
+        for (i, &x) in letters.iter().enumerate() {
+            for &y in &letters[i + 1..] {
+                g.edge_entry(x, y).and_modify(|w| *w += 1).or_insert(1);
+            }
+        }
+    }
+    assert_eq!(g.node_count(), 4);
+    assert_eq!(g.edge_count(), 6);
+    assert_eq!(g.edge_weight('a', 'b'), Some(&2));
+    assert_eq!(g.edge_weight('c', 'a'), Some(&2));
+    assert_eq!(g.edge_weight('b', 'c'), Some(&3));
+    assert_eq!(g.edge_weight('d', 'b'), Some(&1));
+    assert_eq!(g.edge_weight('d', 'd'), Some(&1));
+    assert_eq!(g.neighbors('a').count(), 2);
+    assert_eq!(g.neighbors('d').count(), 3);
+
+    let mut g = DiGraphMap::<_, Vec<u32>>::new();
+    let entry = g.edge_entry(1, 2);
+    assert_eq!(entry.nodes(), (1, 2));
+    entry.or_default().push(1);
+    g.edge_entry(1, 2).or_default().push(2);
+    g.edge_entry(2, 1)
+        .and_modify(|w| w.push(0))
+        .or_default()
+        .push(3);
+    assert_eq!(g.edge_weight(1, 2), Some(&vec![1, 2]));
+    assert_eq!(g.edge_weight(2, 1), Some(&vec![3]));
+    assert_eq!(
+        g.neighbors_directed(1, Incoming).collect::<Vec<_>>(),
+        vec![2]
+    );
+}
+
+#[test]
+fn add_edges() {
+    let mut g = DiGraphMap::new();
+    g.add_edge("a", "b", 0);
+    let added = g.add_edges(&[("a", "b", 1), ("b", "c", 2), ("c", "b", 3), ("b", "c", 4)]);
+    assert_eq!(added, 2);
+    assert_eq!(g.edge_count(), 3);
+    assert_eq!(g.edge_weight("a", "b"), Some(&1));
+    assert_eq!(g.edge_weight("b", "c"), Some(&4));
+    assert_eq!(g.edge_weight("c", "b"), Some(&3));
+    assert_eq!(g.add_edges(&[]), 0);
+}
+
+#[test]
+fn from_adjacency_matrix() {
+    use petgraph::graphmap::InvalidAdjacencyMatrix;
+
+    let labels = [10, 20, 30];
+    let matrix = vec![
+        vec![true, true, false],
+        vec![false, false, true],
+        vec![true, false, false],
+    ];
+    let g = DiGraphMap::from_adjacency_matrix(&labels, &matrix).unwrap();
+    assert_eq!(g.nodes().collect::<Vec<_>>(), labels);
+    assert_eq!(g.edge_count(), 4);
+    let round_trip: Vec<Vec<bool>> = labels
+        .iter()
+        .map(|&a| labels.iter().map(|&b| g.contains_edge(a, b)).collect())
+        .collect();
+    assert_eq!(round_trip, matrix);
+
+    // undirected graphs accept a full matrix or a triangle alike
+    let upper = vec![
+        vec![false, true, true],
+        vec![false, false, true],
+        vec![false, false, false],
+    ];
+    let symmetric = vec![
+        vec![false, true, true],
+        vec![true, false, true],
+        vec![true, true, false],
+    ];
+    let a = UnGraphMap::from_adjacency_matrix(&labels, &upper).unwrap();
+    let b = UnGraphMap::from_adjacency_matrix(&labels, &symmetric).unwrap();
+    assert_eq!(a.edge_count(), 3);
+    assert_eq!(
+        a.all_edges().collect::<Vec<_>>(),
+        b.all_edges().collect::<Vec<_>>()
+    );
+
+    let not_square = vec![vec![false; 3], vec![false; 2], vec![false; 3]];
+    assert_eq!(
+        DiGraphMap::from_adjacency_matrix(&labels, &not_square).unwrap_err(),
+        InvalidAdjacencyMatrix(())
+    );
+    assert!(DiGraphMap::from_adjacency_matrix(&labels[..2], &matrix).is_err());
+}
+
+#[test]
+fn to_adjacency_matrix() {
+    let mut g = UnGraphMap::<_, ()>::from_edges(&[(3, 1), (1, 2), (2, 2), (4, 3)]);
+    g.add_node(5);
+    let (labels, matrix) = g.to_adjacency_matrix();
+    assert_eq!(labels, vec![3, 1, 2, 4, 5]);
+    assert_eq!(matrix.len(), 5);
+    for (i, row) in matrix.iter().enumerate() {
+        assert_eq!(row.len(), 5);
+        for (j, &edge) in row.iter().enumerate() {
+            assert_eq!(edge, matrix[j][i]);
+            assert_eq!(edge, g.contains_edge(labels[i], labels[j]));
+        }
+    }
+    assert!(matrix[2][2]);
+    assert!(!matrix[4].iter().any(|&edge| edge));
+
+    let d = DiGraphMap::<_, ()>::from_edges(&[(1, 2), (2, 3)]);
+    let (labels, matrix) = d.to_adjacency_matrix();
+    let back = DiGraphMap::from_adjacency_matrix(&labels, &matrix).unwrap();
+    assert_eq!(
+        back.all_edges().collect::<Vec<_>>(),
+        d.all_edges().collect::<Vec<_>>()
+    );
+    assert!(matrix[0][1] && !matrix[1][0]);
+}
+
+#[test]
+fn find_edge() {
+    use petgraph::visit::EdgeIndexable;
+
+    let g = UnGraphMap::<_, ()>::from_edges(&[("b", "a"), ("c", "b")]);
+    assert_eq!(g.find_edge("a", "b"), Some(("a", "b")));
+    assert_eq!(g.find_edge("b", "a"), g.find_edge("a", "b"));
+    assert_eq!(g.find_edge("c", "b"), Some(("b", "c")));
+    assert_eq!(g.find_edge("a", "c"), None);
+    assert_eq!(g.find_edge("a", "z"), None);
+    let id = g.find_edge("c", "b").unwrap();
+    assert_eq!(EdgeIndexable::to_index(&g, id), 1);
+
+    let d = DiGraphMap::<_, ()>::from_edges(&[("b", "a")]);
+    assert_eq!(d.find_edge("b", "a"), Some(("b", "a")));
+    assert_eq!(d.find_edge("a", "b"), None);
+}
+
+#[test]
+fn remove_edges_directed() {
+    let mut g = DiGraphMap::<_, ()>::from_edges(&[
+        ("hub", "a"),
+        ("hub", "b"),
+        ("c", "hub"),
+        ("a", "b"),
+        ("hub", "hub"),
+    ]);
+    assert_eq!(g.remove_edges_directed("hub", Outgoing), 3);
+    assert!(g.contains_node("hub"));
+    assert_eq!(g.node_count(), 4);
+    assert_eq!(g.neighbors_directed("hub", Outgoing).count(), 0);
+    assert_eq!(
+        g.neighbors_directed("hub", Incoming).collect::<Vec<_>>(),
+        vec!["c"]
+    );
+    assert!(g.contains_edge("a", "b"));
+    assert_eq!(g.edge_count(), 2);
+    assert_eq!(g.remove_edges_directed("hub", Outgoing), 0);
+    assert_eq!(g.remove_edges_directed("missing", Outgoing), 0);
+
+    let mut u = UnGraphMap::<_, ()>::from_edges(&[(1, 2), (3, 1), (1, 1), (2, 3)]);
+    assert_eq!(u.remove_edges_directed(1, Incoming), 3);
+    assert_eq!(
+        u.all_edges().map(|(a, b, _)| (a, b)).collect::<Vec<_>>(),
+        vec![(2, 3)]
+    );
+    assert_eq!(u.neighbors(2).collect::<Vec<_>>(), vec![3]);
+}
+
+#[test]
+fn disconnect_node() {
+    let mut g = DiGraphMap::<_, ()>::from_edges(&[(1, 2), (2, 3), (3, 2), (2, 2), (3, 4)]);
+    assert_eq!(g.disconnect_node(2), 4);
+    assert_eq!(g.nodes().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+    assert_eq!(g.neighbors_directed(2, Outgoing).count(), 0);
+    assert_eq!(g.neighbors_directed(2, Incoming).count(), 0);
+    assert_eq!(g.neighbors_directed(1, Outgoing).count(), 0);
+    assert_eq!(
+        g.all_edges().map(|(a, b, _)| (a, b)).collect::<Vec<_>>(),
+        vec![(3, 4)]
+    );
+    assert_eq!(g.disconnect_node(2), 0);
+
+    let mut u = UnGraphMap::<_, ()>::from_edges(&[(1, 2), (2, 3), (3, 3)]);
+    assert_eq!(u.disconnect_node(3), 2);
+    assert_eq!(u.nodes().collect::<Vec<_>>(), vec![1, 2, 3]);
+    assert_eq!(u.neighbors(3).count(), 0);
+    assert_eq!(u.edge_count(), 1);
+}
+
+#[test]
+fn relabel_node() {
+    let mut g = DiGraphMap::<_, u32>::from_edges(&[(1, 2, 12), (2, 3, 23), (3, 2, 32), (4, 1, 41)]);
+    g.relabel_node(2, 20).unwrap();
+    assert_eq!(g.nodes().collect::<Vec<_>>(), vec![1, 20, 3, 4]);
+    assert_eq!(
+        g.all_edges().collect::<Vec<_>>(),
+        vec![(1, 20, &12), (20, 3, &23), (3, 20, &32), (4, 1, &41)]
+    );
+    assert!(!g.contains_node(2));
+    assert_eq!(
+        g.neighbors_directed(20, Incoming).collect::<Vec<_>>(),
+        vec![1, 3]
+    );
+    assert_eq!(g.neighbors(3).collect::<Vec<_>>(), vec![20]);
+    assert_eq!(g.neighbors(1).collect::<Vec<_>>(), vec![20]);
+
+    let mut u = UnGraphMap::<_, u32>::from_edges(&[(1, 2, 12), (2, 3, 23)]);
+    u.relabel_node(1, 5).unwrap();
+    assert_eq!(u.nodes().collect::<Vec<_>>(), vec![5, 2, 3]);
+    assert_eq!(u.edge_weight(2, 5), Some(&12));
+    assert_eq!(u.neighbors(2).collect::<Vec<_>>(), vec![5, 3]);
+}
+
+#[test]
+fn relabel_node_error() {
+    use petgraph::graphmap::RelabelError;
+
+    let mut g = DiGraphMap::<_, u32>::from_edges(&[(1, 2, 12), (2, 3, 23)]);
+    assert_eq!(g.relabel_node(1, 3), Err(RelabelError::NodeExists));
+    assert_eq!(g.relabel_node(7, 8), Err(RelabelError::NodeNotFound));
+    assert_eq!(g.relabel_node(7, 7), Err(RelabelError::NodeNotFound));
+    assert_eq!(g.relabel_node(2, 2), Ok(()));
+    assert_eq!(g.nodes().collect::<Vec<_>>(), vec![1, 2, 3]);
+    assert_eq!(
+        g.all_edges().collect::<Vec<_>>(),
+        vec![(1, 2, &12), (2, 3, &23)]
+    );
+}
+
+#[test]
+fn relabel_node_self_loop() {
+    let mut g = DiGraphMap::<_, u32>::from_edges(&[(1, 1, 11), (1, 2, 12), (2, 1, 21)]);
+    g.relabel_node(1, 9).unwrap();
+    assert_eq!(g.nodes().collect::<Vec<_>>(), vec![9, 2]);
+    assert_eq!(
+        g.all_edges().collect::<Vec<_>>(),
+        vec![(9, 9, &11), (9, 2, &12), (2, 9, &21)]
+    );
+    assert_eq!(
+        g.neighbors_directed(9, Outgoing).collect::<Vec<_>>(),
+        vec![9, 2]
+    );
+    assert_eq!(
+        g.neighbors_directed(9, Incoming).collect::<Vec<_>>(),
+        vec![9, 2]
+    );
+    assert_eq!(g.remove_edge(9, 9), Some(11));
+
+    let mut u = UnGraphMap::<_, u32>::from_edges(&[(3, 3, 33), (3, 1, 13)]);
+    u.relabel_node(3, 0).unwrap();
+    assert_eq!(
+        u.all_edges().collect::<Vec<_>>(),
+        vec![(0, 0, &33), (0, 1, &13)]
+    );
+    assert_eq!(u.neighbors(0).collect::<Vec<_>>(), vec![0, 1]);
+    assert_eq!(u.neighbors(1).collect::<Vec<_>>(), vec![0]);
+}
+
+#[test]
+#[cfg(feature = "rayon")]
+fn test_parallel_edge_iterator() {
+    use rayon::prelude::*;
+    let gr: DiGraphMap<u32, u64> = (0..1000).map(|i| (i, (i * 7) % 1000, i as u64)).collect();
+
+    let serial_sum: u64 = gr.all_edges().map(|(_, _, w)| *w).sum();
+    let parallel_sum: u64 = gr.par_all_edges().map(|(_, _, w)| *w).sum();
+    assert_eq!(serial_sum, parallel_sum);
+
+    let into_par_sum: u64 = (&gr).into_par_iter().map(|(a, b, _)| (a + b) as u64).sum();
+    let serial_endpoints: u64 = gr.all_edges().map(|(a, b, _)| (a + b) as u64).sum();
+    assert_eq!(into_par_sum, serial_endpoints);
+    assert_eq!(gr.par_all_edges().count(), gr.edge_count());
+}
+
+#[test]
+fn try_from_graph() {
+    use petgraph::graphmap::ConversionError;
+    use std::convert::TryFrom;
+
+    let mut gr: Graph<u32, u32, Directed> = Graph::new();
+    let a = gr.add_node(10);
+    let b = gr.add_node(20);
+    let c = gr.add_node(30);
+    gr.add_edge(a, b, 1);
+    gr.add_edge(b, a, 2);
+    gr.add_edge(c, c, 3);
+
+    let graph = DiGraphMap::<u32, u32>::try_from(gr.clone()).unwrap();
+    assert_eq!(graph.nodes().collect::<Vec<_>>(), vec![10, 20, 30]);
+    assert_eq!(
+        graph.all_edges().collect::<Vec<_>>(),
+        vec![(10, 20, &1), (20, 10, &2), (30, 30, &3)]
+    );
+
+    let mut parallel = gr.clone();
+    parallel.add_edge(a, b, 4);
+    assert_eq!(
+        DiGraphMap::<u32, u32>::try_from(parallel).unwrap_err(),
+        ConversionError::ParallelEdge
+    );
+
+    let mut duplicate = gr.clone();
+    duplicate.add_node(20);
+    assert_eq!(
+        DiGraphMap::<u32, u32>::try_from(duplicate).unwrap_err(),
+        ConversionError::DuplicateNode
+    );
+
+    // Edges in opposite directions are parallel in an undirected graph.
+    let mut un: Graph<u32, u32, Undirected> = Graph::new_undirected();
+    let a = un.add_node(1);
+    let b = un.add_node(2);
+    un.add_edge(a, b, 1);
+    assert!(UnGraphMap::<u32, u32>::try_from(un.clone()).is_ok());
+    un.add_edge(b, a, 2);
+    assert_eq!(
+        UnGraphMap::<u32, u32>::try_from(un).unwrap_err(),
+        ConversionError::ParallelEdge
+    );
+}
+
+#[test]
+#[cfg(feature = "stable_graph")]
+fn stable_graph_round_trip() {
+    use petgraph::stable_graph::StableDiGraph;
+
+    let gm = DiGraphMap::<_, u32>::from_edges(&[(1, 2, 12), (2, 3, 23), (3, 1, 31), (4, 4, 44)]);
+    let mut sg: StableDiGraph<u32, u32> = gm.clone().into_stable_graph();
+    assert_eq!(sg.node_count(), 4);
+    assert_eq!(sg.edge_count(), 4);
+
+    let two = sg.node_indices().find(|&n| sg[n] == 2).unwrap();
+    sg.remove_node(two);
+    let five = sg.add_node(5);
+    let four = sg.node_indices().find(|&n| sg[n] == 4).unwrap();
+    sg.add_edge(five, four, 54);
+
+    // the new edge reuses the vacant slot of a removed one
+    let back = DiGraphMap::<u32, u32>::from_stable_graph(sg.clone());
+    assert_eq!(back.node_count(), 4);
+    assert!(!back.contains_node(2));
+    assert_eq!(
+        back.all_edges().collect::<Vec<_>>(),
+        vec![(5, 4, &54), (3, 1, &31), (4, 4, &44)]
+    );
+
+    let again: StableDiGraph<u32, u32> = back.clone().into_stable_graph();
+    assert_eq!(again.node_count(), 4);
+    let edges: Vec<_> = again
+        .edge_indices()
+        .map(|e| {
+            let (a, b) = again.edge_endpoints(e).unwrap();
+            (again[a], again[b], again[e])
+        })
+        .collect();
+    assert_eq!(edges, vec![(5, 4, 54), (3, 1, 31), (4, 4, 44)]);
+}
+
+#[test]
+fn reversed_view() {
+    use petgraph::visit::{EdgeRef, IntoEdgesDirected, IntoNeighborsDirected, Reversed};
+
+    let gr = DiGraphMap::<_, u32>::from_edges(&[(1, 2, 12), (1, 3, 13), (3, 2, 32), (2, 2, 22)]);
+    let rev = Reversed(&gr);
+    for n in gr.nodes() {
+        assert_eq!(
+            rev.neighbors_directed(n, Outgoing).collect::<Vec<_>>(),
+            gr.neighbors_directed(n, Incoming).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            rev.neighbors_directed(n, Incoming).collect::<Vec<_>>(),
+            gr.neighbors_directed(n, Outgoing).collect::<Vec<_>>()
+        );
+        let rev_edges: Vec<_> = rev
+            .edges_directed(n, Outgoing)
+            .map(|e| (e.source(), e.target(), *e.weight()))
+            .collect();
+        let in_edges: Vec<_> = gr
+            .edges_directed(n, Incoming)
+            .map(|(a, b, w)| (b, a, *w))
+            .collect();
+        assert_eq!(rev_edges, in_edges);
+    }
+    assert_eq!(
+        Dfs::new(rev, 2).iter(rev).collect::<Vec<_>>(),
+        vec![2, 3, 1]
+    );
+    assert!(gr.contains_edge(1, 2));
+}
+
+#[test]
+fn get_or_add_node() {
+    let mut g = DiGraphMap::<_, ()>::new();
+    assert_eq!(g.get_or_add_node(1), (1, true));
+    assert_eq!(g.get_or_add_node(1), (1, false));
+    g.add_edge(2, 3, ());
+    assert_eq!(g.get_or_add_node(3), (3, false));
+    assert_eq!(g.get_or_add_node(4), (4, true));
+    assert_eq!(g.nodes().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+    assert_eq!(
+        g.neighbors_directed(3, Incoming).collect::<Vec<_>>(),
+        vec![2]
+    );
+}
+
+#[test]
+fn hash_independent_of_order() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    fn hash_of<T: Hash>(value: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let mut a = UnGraphMap::<_, u32>::new();
+    a.add_node(5);
+    a.add_edge(1, 2, 12);
+    a.add_edge(3, 2, 23);
+    a.add_edge(3, 3, 33);
+
+    let mut b = UnGraphMap::<_, u32>::new();
+    b.add_edge(3, 3, 33);
+    b.add_edge(2, 3, 23);
+    b.add_node(5);
+    b.add_edge(2, 1, 12);
+    assert_eq!(hash_of(&a), hash_of(&b));
+
+    // building through removals gives the same hash as well
+    let mut c = b.clone();
+    c.add_edge(5, 1, 0);
+    c.add_node(7);
+    c.remove_edge(1, 5);
+    c.remove_node(7);
+    assert_eq!(hash_of(&a), hash_of(&c));
+
+    b.add_edge(1, 2, 21);
+    assert_ne!(hash_of(&a), hash_of(&b));
+    let mut d = a.clone();
+    d.add_node(6);
+    assert_ne!(hash_of(&a), hash_of(&d));
+}
+
+#[test]
+fn update_edge() {
+    let stream = [(1, 2), (2, 3), (1, 2), (2, 1), (1, 2), (3, 3), (3, 3)];
+
+    let mut g = DiGraphMap::<_, u32>::new();
+    let mut created = 0;
+    for &(a, b) in &stream {
+        if g.update_edge(a, b, 1, |count, one| *count += one) {
+            created += 1;
+        }
+    }
+    assert_eq!(created, 4);
+    assert_eq!(
+        g.all_edges().collect::<Vec<_>>(),
+        vec![(1, 2, &3), (2, 3, &1), (2, 1, &1), (3, 3, &2)]
+    );
+    assert_eq!(g.neighbors(1).collect::<Vec<_>>(), vec![2]);
+
+    let mut u = UnGraphMap::<_, u32>::new();
+    for &(a, b) in &stream {
+        u.update_edge(a, b, 1, |count, one| *count += one);
+    }
+    assert_eq!(
+        u.all_edges().collect::<Vec<_>>(),
+        vec![(1, 2, &4), (2, 3, &1), (3, 3, &2)]
+    );
+}
+
+#[test]
+fn snapshot_edges() {
+    let mut g = UnGraphMap::<_, String>::new();
+    g.add_edge(3, 1, "a".to_string());
+    g.add_edge(1, 2, "b".to_string());
+    g.add_edge(2, 2, "c".to_string());
+    g.add_node(4);
+
+    let snapshot = g.snapshot_edges();
+    assert_eq!(snapshot.len(), g.edge_count());
+    for ((a, b, w), (sa, sb, sw)) in g.all_edges().zip(&snapshot) {
+        assert_eq!((a, b, w), (*sa, *sb, sw));
+    }
+
+    let copy = snapshot.clone();
+    g.remove_edge(1, 2);
+    g.edge_weight_mut(1, 3).unwrap().push('!');
+    assert_eq!(snapshot, copy);
+    assert_eq!(snapshot[0], (1, 3, "a".to_string()));
+    assert_eq!(format!("{:?}", snapshot[2]), r#"(2, 2, "c")"#);
+}
+
+#[test]
+fn ptr_interior_mutability() {
+    use petgraph::graphmap::Ptr;
+    use std::cell::{Cell, RefCell};
+
+    let nodes = [Cell::new(0), Cell::new(0), Cell::new(0)];
+    let mut g = UnGraphMap::new();
+    g.add_edge(Ptr(&nodes[0]), Ptr(&nodes[1]), ());
+    g.add_edge(Ptr(&nodes[1]), Ptr(&nodes[2]), ());
+    for node in g.nodes() {
+        node.set(g.neighbors(node).count());
+    }
+    assert_eq!(
+        nodes.iter().map(Cell::get).collect::<Vec<_>>(),
+        vec![1, 2, 1]
+    );
+    // equal payloads are still distinct nodes
+    assert_eq!(g.node_count(), 3);
+    assert!(!g.contains_edge(Ptr(&nodes[0]), Ptr(&nodes[2])));
+
+    let label = RefCell::new(vec![1]);
+    let other = RefCell::new(vec![1]);
+    let mut d = DiGraphMap::new();
+    d.add_edge(Ptr(&label), Ptr(&other), 5);
+    label.borrow_mut().push(2);
+    assert_eq!(d.edge_weight(Ptr(&label), Ptr(&other)), Some(&5));
+    assert_eq!(d.nodes().next().unwrap().borrow().len(), 2);
+}
+
+#[test]
+fn ptr_identity() {
+    use petgraph::graphmap::Ptr;
+
+    let values = [String::from("x"), String::from("x")];
+    let a = Ptr::new(&values[0]);
+    let b = Ptr::new(&values[1]);
+    assert_eq!(*a, *b);
+    assert_ne!(a, b);
+    assert_eq!(a, Ptr::new(&values[0]));
+    assert_eq!(a, Ptr(&values[0]));
+    assert_eq!(a.as_ptr(), &values[0] as *const String);
+    assert_ne!(a.as_ptr(), b.as_ptr());
+    assert_eq!(a < b, a.as_ptr() < b.as_ptr());
+
+    let mut g = UnGraphMap::new();
+    g.add_edge(a, b, ());
+    assert_eq!(g.node_count(), 2);
+    assert!(g.contains_edge(Ptr::new(&values[1]), Ptr::new(&values[0])));
+}
+
+#[test]
+fn bfs_dfs_nodes() {
+    //       1
+    //     /   \
+    //    2     3
+    //   / \     \
+    //  4   5     6
+    let tree = UnGraphMap::<_, ()>::from_edges(&[(1, 2), (1, 3), (2, 4), (2, 5), (3, 6)]);
+    assert_eq!(tree.bfs(1).collect::<Vec<_>>(), vec![1, 2, 3, 4, 5, 6]);
+    assert_eq!(tree.dfs(1).collect::<Vec<_>>(), vec![1, 3, 6, 2, 5, 4]);
+    assert_eq!(tree.bfs(2).collect::<Vec<_>>(), vec![2, 1, 4, 5, 3, 6]);
+    assert_eq!(tree.bfs(7).count(), 0);
+    assert_eq!(tree.dfs(7).count(), 0);
+
+    // only follows outgoing edges in a directed graph
+    let mut directed = DiGraphMap::<_, ()>::from_edges(&[(1, 2), (1, 3), (2, 4), (2, 5), (3, 6)]);
+    assert_eq!(directed.bfs(2).collect::<Vec<_>>(), vec![2, 4, 5]);
+    assert_eq!(directed.dfs(3).collect::<Vec<_>>(), vec![3, 6]);
+    directed.add_node(8);
+    assert_eq!(directed.dfs(8).collect::<Vec<_>>(), vec![8]);
+
+    let mut bfs = tree.bfs(1);
+    bfs.next();
+    let rest = bfs.clone().collect::<Vec<_>>();
+    assert_eq!(rest, bfs.collect::<Vec<_>>());
+}
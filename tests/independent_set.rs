@@ -0,0 +1,45 @@
+#![cfg(feature = "graphmap")]
+
+use petgraph::algo::greedy_independent_set;
+use petgraph::prelude::*;
+
+fn assert_maximal_independent(graph: &UnGraphMap<u32, ()>, set: &[u32]) {
+    for &a in set {
+        for &b in set {
+            assert!(
+                a == b || !graph.contains_edge(a, b),
+                "{} and {} are adjacent",
+                a,
+                b
+            );
+        }
+    }
+    for n in graph.nodes() {
+        assert!(
+            set.contains(&n) || graph.neighbors(n).any(|m| set.contains(&m)),
+            "{} could be added",
+            n
+        );
+    }
+}
+
+#[test]
+fn greedy_independent_set_path() {
+    // 0 - 1 - 2 - 3 - 4 - 5 - 6
+    let graph = UnGraphMap::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 6)]);
+    let mut set = greedy_independent_set(&graph);
+    assert_maximal_independent(&graph, &set);
+    set.sort();
+    assert_eq!(set, vec![0, 2, 4, 6]);
+}
+
+#[test]
+fn greedy_independent_set_misc() {
+    let mut graph = UnGraphMap::from_edges(&[(0, 1), (1, 2), (2, 0), (2, 3), (3, 3)]);
+    graph.add_node(9);
+    let set = greedy_independent_set(&graph);
+    assert_maximal_independent(&graph, &set);
+    assert!(set.contains(&9));
+
+    assert!(greedy_independent_set(&UnGraphMap::<u32, ()>::new()).is_empty());
+}
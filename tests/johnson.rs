@@ -0,0 +1,50 @@
+use petgraph::algo::{floyd_warshall, johnson};
+use petgraph::{prelude::*, Graph};
+
+#[test]
+fn johnson_mixed_sign_weights() {
+    let mut graph: Graph<(), i32, Directed> = Graph::new();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    let d = graph.add_node(());
+    let e = graph.add_node(());
+    graph.extend_with_edges(&[
+        (a, b, 3),
+        (a, c, 8),
+        (a, e, -4),
+        (b, d, 1),
+        (b, e, 7),
+        (c, b, 4),
+        (d, a, 2),
+        (d, c, -5),
+        (e, d, 6),
+    ]);
+
+    let res = johnson(&graph, |edge| *edge.weight()).unwrap();
+    assert_eq!(res, floyd_warshall(&graph, |edge| *edge.weight()).unwrap());
+    assert_eq!(res[&(a, b)], 1);
+    assert_eq!(res[&(a, c)], -3);
+    assert_eq!(res[&(e, b)], 5);
+    assert_eq!(res[&(c, e)], 3);
+}
+
+#[test]
+fn johnson_unreachable_and_negative_cycle() {
+    let mut graph: Graph<(), f64, Directed> = Graph::new();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    graph.extend_with_edges(&[(a, b, -1.5), (b, c, 2.)]);
+    let res = johnson(&graph, |edge| *edge.weight()).unwrap();
+    assert_eq!(res, floyd_warshall(&graph, |edge| *edge.weight()).unwrap());
+    assert_eq!(res[&(a, c)], 0.5);
+    assert_eq!(res[&(c, a)], std::f64::MAX);
+
+    graph.add_edge(c, a, -1.);
+    assert!(johnson(&graph, |edge| *edge.weight()).is_err());
+
+    // a negative undirected edge can be traversed back and forth
+    let graph: Graph<(), i32, Undirected> = Graph::from_edges(&[(0, 1, 2), (1, 2, -1)]);
+    assert!(johnson(&graph, |edge| *edge.weight()).is_err());
+}
@@ -1,4 +1,4 @@
-use petgraph::algo::k_shortest_path;
+use petgraph::algo::{k_shortest_path, k_shortest_simple_paths};
 use petgraph::prelude::*;
 use petgraph::Graph;
 use std::collections::HashMap;
@@ -61,3 +61,53 @@ fn second_shortest_path() {
 
     assert_eq!(res, expected_res);
 }
+
+#[test]
+fn k_shortest_simple_paths_diamond() {
+    //     b
+    //   /   \
+    //  a --- d
+    //   \   /
+    //     c
+    let mut graph: Graph<(), u32, Directed> = Graph::new();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    let d = graph.add_node(());
+    graph.extend_with_edges(&[
+        (a, b, 1),
+        (b, d, 1),
+        (a, c, 2),
+        (c, d, 2),
+        (a, d, 10),
+        (b, c, 0),
+        (c, b, 2),
+    ]);
+
+    let paths = k_shortest_simple_paths(&graph, a, d, 3, |e| *e.weight());
+    assert_eq!(
+        paths,
+        vec![
+            (2, vec![a, b, d]),
+            (3, vec![a, b, c, d]),
+            (4, vec![a, c, d]),
+        ]
+    );
+
+    let all = k_shortest_simple_paths(&graph, a, d, 10, |e| *e.weight());
+    let costs: Vec<u32> = all.iter().map(|p| p.0).collect();
+    assert_eq!(costs, vec![2, 3, 4, 5, 10]);
+    for (_, path) in &all {
+        let mut nodes = path.clone();
+        nodes.sort();
+        nodes.dedup();
+        assert_eq!(nodes.len(), path.len(), "{:?} is not loopless", path);
+    }
+
+    assert!(k_shortest_simple_paths(&graph, a, d, 0, |e| *e.weight()).is_empty());
+    assert!(k_shortest_simple_paths(&graph, d, a, 3, |e| *e.weight()).is_empty());
+    assert_eq!(
+        k_shortest_simple_paths(&graph, a, a, 3, |e| *e.weight()),
+        vec![(0, vec![a])]
+    );
+}
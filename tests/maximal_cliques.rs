@@ -0,0 +1,38 @@
+#![cfg(feature = "graphmap")]
+
+use petgraph::algo::maximal_cliques;
+use petgraph::prelude::*;
+
+fn sorted_cliques(graph: &UnGraphMap<u32, ()>) -> Vec<Vec<u32>> {
+    let mut cliques: Vec<Vec<u32>> = maximal_cliques(graph)
+        .into_iter()
+        .map(|mut clique| {
+            clique.sort();
+            clique
+        })
+        .collect();
+    cliques.sort();
+    cliques
+}
+
+#[test]
+fn maximal_cliques_overlapping_triangles() {
+    // two triangles sharing the edge 1 - 2
+    let graph = UnGraphMap::from_edges(&[(0, 1), (1, 2), (2, 0), (1, 3), (2, 3)]);
+    assert_eq!(sorted_cliques(&graph), vec![vec![0, 1, 2], vec![1, 2, 3]]);
+}
+
+#[test]
+fn maximal_cliques_complete_graph() {
+    let graph = UnGraphMap::from_edges(&[(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+    assert_eq!(sorted_cliques(&graph), vec![vec![0, 1, 2, 3]]);
+}
+
+#[test]
+fn maximal_cliques_isolated_and_self_loops() {
+    let mut graph = UnGraphMap::from_edges(&[(0, 1), (1, 1)]);
+    graph.add_node(5);
+    assert_eq!(sorted_cliques(&graph), vec![vec![0, 1], vec![5]]);
+
+    assert!(sorted_cliques(&UnGraphMap::new()).is_empty());
+}
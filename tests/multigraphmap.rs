@@ -0,0 +1,59 @@
+#![cfg(feature = "multigraphmap")]
+
+use petgraph::multigraphmap::{DiMultiGraphMap, UnMultiGraphMap};
+use petgraph::Direction::Incoming;
+
+#[test]
+fn parallel_edges() {
+    let mut g = DiMultiGraphMap::new();
+    let first = g.add_edge(1, 2, "a");
+    let second = g.add_edge(1, 2, "b");
+    g.add_edge(2, 1, "c");
+    assert_ne!(first, second);
+    assert_eq!(g.node_count(), 2);
+    assert_eq!(g.edge_count(), 3);
+
+    let edges: Vec<_> = g.edges_connecting(1, 2).collect();
+    assert_eq!(edges, vec![(first, &"a"), (second, &"b")]);
+    assert_eq!(g.edges_connecting(2, 1).count(), 1);
+    assert_eq!(g.edges_connecting(1, 3).count(), 0);
+
+    // parallel edges lead to a single neighbor
+    assert_eq!(g.neighbors(1).collect::<Vec<_>>(), vec![2]);
+    assert_eq!(
+        g.neighbors_directed(1, Incoming).collect::<Vec<_>>(),
+        vec![2]
+    );
+}
+
+#[test]
+fn parallel_edges_undirected() {
+    let mut g = UnMultiGraphMap::new();
+    let first = g.add_edge(1, 2, 1);
+    let second = g.add_edge(2, 1, 2);
+    let weights: Vec<_> = g.edges_connecting(2, 1).map(|(_, &w)| w).collect();
+    assert_eq!(weights, vec![1, 2]);
+    assert_eq!(g.edge_weight(2, 1, first), Some(&1));
+    *g.edge_weight_mut(1, 2, second).unwrap() += 10;
+    assert_eq!(g.edge_weight(1, 2, second), Some(&12));
+}
+
+#[test]
+fn remove_parallel_edges() {
+    let mut g = DiMultiGraphMap::new();
+    let first = g.add_edge(1, 2, ());
+    let second = g.add_edge(1, 2, ());
+    g.add_edge(2, 3, ());
+
+    assert_eq!(g.remove_edge(1, 2, first), Some(()));
+    assert_eq!(g.remove_edge(1, 2, first), None);
+    assert!(g.contains_edge(1, 2));
+    assert_eq!(g.remove_edge(1, 2, second), Some(()));
+    assert!(!g.contains_edge(1, 2));
+    assert_eq!(g.neighbors(1).count(), 0);
+
+    g.add_edge(3, 2, ());
+    assert!(g.remove_node(2));
+    assert_eq!(g.edge_count(), 0);
+    assert_eq!(g.nodes().collect::<Vec<_>>(), vec![1, 3]);
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "graphmap")]
+
+use petgraph::algo::weighted_pagerank;
+use petgraph::prelude::*;
+
+#[test]
+fn weighted_pagerank_sums_to_one() {
+    let graph = DiGraphMap::<_, f64>::from_edges(&[(0, 1, 1.), (1, 2, 2.), (2, 0, 3.), (2, 3, 1.)]);
+    let rank = weighted_pagerank(&graph, 0.85, 100, 1e-12, |(_, _, w)| *w);
+    assert_eq!(rank.len(), 4);
+    let total: f64 = rank.values().sum();
+    assert!((total - 1.).abs() < 1e-9);
+}
+
+#[test]
+fn weighted_pagerank_heavy_edge() {
+    // node 0 links to both 1 and 2, but much more strongly to 2
+    let graph =
+        DiGraphMap::<_, f64>::from_edges(&[(0, 1, 1.), (0, 2, 10.), (1, 0, 1.), (2, 0, 1.)]);
+    let unweighted = weighted_pagerank(&graph, 0.85, 100, 1e-12, |_| 1.);
+    let weighted = weighted_pagerank(&graph, 0.85, 100, 1e-12, |(_, _, w)| *w);
+
+    assert!((unweighted[&1] - unweighted[&2]).abs() < 1e-9);
+    assert!(weighted[&2] > unweighted[&2]);
+    assert!(weighted[&1] < unweighted[&1]);
+    assert!(weighted[&2] > weighted[&1]);
+}
+
+#[test]
+fn weighted_pagerank_dangling() {
+    // node 2 has no outgoing edges and spreads its rank uniformly
+    let graph = DiGraphMap::<_, f64>::from_edges(&[(0, 2, 1.), (1, 2, 1.)]);
+    let rank = weighted_pagerank(&graph, 0.85, 100, 1e-12, |(_, _, w)| *w);
+    let total: f64 = rank.values().sum();
+    assert!((total - 1.).abs() < 1e-9);
+    assert!((rank[&0] - rank[&1]).abs() < 1e-12);
+    assert!(rank[&2] > rank[&0]);
+}
@@ -0,0 +1,62 @@
+#![cfg(all(feature = "rayon", feature = "graphmap"))]
+
+use petgraph::algo::{connected_components, par_connected_components};
+use petgraph::prelude::*;
+use petgraph::unionfind::UnionFind;
+
+#[test]
+fn par_connected_components_matches_sequential() {
+    // a pseudo-random sparse graph with many components
+    let mut graph = UnGraph::<(), ()>::with_capacity(2000, 1200);
+    for _ in 0..2000 {
+        graph.add_node(());
+    }
+    let mut x: u64 = 12345;
+    for _ in 0..1200 {
+        x = x
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        let a = (x >> 33) as usize % 2000;
+        x = x
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        let b = (x >> 33) as usize % 2000;
+        graph.add_edge(NodeIndex::new(a), NodeIndex::new(b), ());
+    }
+
+    let (count, labels) = par_connected_components(&graph);
+    assert_eq!(count, connected_components(&graph));
+    assert_eq!(labels.len(), 2000);
+
+    let mut sets = UnionFind::new(2000);
+    for edge in graph.edge_references() {
+        sets.union(edge.source().index(), edge.target().index());
+    }
+    for i in 0..2000 {
+        // the labeling is the same as the sequential one, up to relabeling
+        let root = sets.find(i);
+        assert_eq!(labels[i], labels[root]);
+        assert!(labels[i] < count);
+    }
+    for edge in graph.edge_references() {
+        assert_eq!(labels[edge.source().index()], labels[edge.target().index()]);
+    }
+    let mut roots: Vec<usize> = (0..2000).map(|i| sets.find(i)).collect();
+    roots.sort_unstable();
+    roots.dedup();
+    assert_eq!(roots.len(), count);
+}
+
+#[test]
+fn par_connected_components_directed() {
+    let mut graph = DiGraphMap::<u32, ()>::from_edges(&[(5, 0), (1, 2), (3, 2)]);
+    graph.add_node(4);
+    assert_eq!(
+        par_connected_components(&graph),
+        (3, vec![0, 0, 1, 1, 1, 2])
+    );
+    assert_eq!(
+        par_connected_components(&DiGraphMap::<u32, ()>::new()),
+        (0, vec![])
+    );
+}
@@ -23,10 +23,10 @@ use quickcheck::{Arbitrary, Gen};
 use rand::Rng;
 
 use petgraph::algo::{
-    bellman_ford, condensation, dijkstra, find_negative_cycle, floyd_warshall,
-    greedy_feedback_arc_set, greedy_matching, is_cyclic_directed, is_cyclic_undirected,
-    is_isomorphic, is_isomorphic_matching, k_shortest_path, kosaraju_scc, maximum_matching,
-    min_spanning_tree, tarjan_scc, toposort, Matching,
+    bellman_ford, bidirectional_dijkstra, condensation, dijkstra, find_negative_cycle,
+    floyd_warshall, greedy_feedback_arc_set, greedy_matching, is_cyclic_directed,
+    is_cyclic_undirected, is_isomorphic, is_isomorphic_matching, johnson, k_shortest_path,
+    kosaraju_scc, maximum_matching, min_spanning_tree, tarjan_scc, toposort, Matching,
 };
 use petgraph::data::FromElements;
 use petgraph::dot::{Config, Dot};
@@ -786,6 +786,35 @@ quickcheck! {
     }
 }
 
+#[test]
+fn bidirectional_dijkstra_matches_dijkstra() {
+    fn prop<Ty: EdgeType>(g: Graph<(), u32, Ty>, a: usize, b: usize) -> bool {
+        if g.node_count() == 0 {
+            return true;
+        }
+        let a = node_index(a % g.node_count());
+        let b = node_index(b % g.node_count());
+        let distances = dijkstra(&g, a, Some(b), |e| *e.weight());
+        match bidirectional_dijkstra(&g, a, b, |e| *e.weight()) {
+            None => !distances.contains_key(&b),
+            Some((cost, path)) => {
+                let path_cost = path.windows(2).map(|w| {
+                    g.edges_connecting(w[0], w[1])
+                        .map(|e| *e.weight())
+                        .min()
+                        .unwrap()
+                });
+                distances.get(&b) == Some(&cost)
+                    && path.first() == Some(&a)
+                    && path.last() == Some(&b)
+                    && path_cost.sum::<u32>() == cost
+            }
+        }
+    }
+    quickcheck::quickcheck(prop as fn(Graph<_, _, Directed>, _, _) -> bool);
+    quickcheck::quickcheck(prop as fn(Graph<_, _, Undirected>, _, _) -> bool);
+}
+
 quickcheck! {
     // checks that the distances computed by k'th shortest path is always greater or equal compared to their dijkstra computation
     fn k_shortest_path_(g: Graph<u32, u32>, node: usize) -> bool {
@@ -1312,3 +1341,30 @@ quickcheck! {
         true
     }
 }
+
+#[test]
+fn johnson_matches_floyd_warshall() {
+    fn prop<Ty: EdgeType>(mut g: Graph<(), i8, Ty>) -> bool {
+        // floyd_warshall keeps the weight of the last of several parallel
+        // edges and ignores self loops, so only compare on simple graphs
+        let mut seen = HashSet::new();
+        g.retain_edges(|g, e| {
+            let (a, b) = g.edge_endpoints(e).unwrap();
+            let key = if g.is_directed() || a < b {
+                (a, b)
+            } else {
+                (b, a)
+            };
+            a != b && seen.insert(key)
+        });
+        let fw_res = floyd_warshall(&g, |e| i32::from(*e.weight()));
+        let johnson_res = johnson(&g, |e| i32::from(*e.weight()));
+        match (fw_res, johnson_res) {
+            (Ok(fw), Ok(j)) => fw == j,
+            (Err(_), Err(_)) => true,
+            _ => false,
+        }
+    }
+    quickcheck::quickcheck(prop as fn(Graph<_, _, Directed>) -> bool);
+    quickcheck::quickcheck(prop as fn(Graph<_, _, Undirected>) -> bool);
+}
@@ -0,0 +1,87 @@
+#![cfg(all(feature = "rand", feature = "graphmap"))]
+
+use petgraph::algo::{connected_components, is_cyclic_undirected, uniform_spanning_tree};
+use petgraph::prelude::*;
+use rand::{ChaChaRng, SeedableRng};
+
+fn grid(side: u32) -> UnGraphMap<u32, ()> {
+    let mut graph = UnGraphMap::new();
+    for x in 0..side {
+        for y in 0..side {
+            let n = x * side + y;
+            graph.add_node(n);
+            if x + 1 < side {
+                graph.add_edge(n, n + side, ());
+            }
+            if y + 1 < side {
+                graph.add_edge(n, n + 1, ());
+            }
+        }
+    }
+    graph
+}
+
+#[test]
+fn uniform_spanning_tree_is_spanning_tree() {
+    let graph = grid(5);
+    let mut rng = ChaChaRng::from_seed([42; 32]);
+    for _ in 0..10 {
+        let tree = uniform_spanning_tree(&graph, &mut rng).unwrap();
+        assert_eq!(tree.node_count(), graph.node_count());
+        assert_eq!(tree.edge_count(), graph.node_count() - 1);
+        assert_eq!(connected_components(&tree), 1);
+        assert!(!is_cyclic_undirected(&tree));
+        for (a, b, _) in tree.all_edges() {
+            assert!(graph.contains_edge(a, b));
+        }
+    }
+}
+
+#[test]
+fn uniform_spanning_tree_reproducible() {
+    let graph = grid(4);
+    let sample = |seed| {
+        let mut rng = ChaChaRng::from_seed([seed; 32]);
+        let tree = uniform_spanning_tree(&graph, &mut rng).unwrap();
+        let mut edges: Vec<_> = tree.all_edges().map(|(a, b, _)| (a, b)).collect();
+        edges.sort();
+        edges
+    };
+    assert_eq!(sample(1), sample(1));
+    assert_ne!(sample(1), sample(2));
+}
+
+#[test]
+fn uniform_spanning_tree_disconnected() {
+    let mut graph = UnGraphMap::<_, ()>::from_edges(&[(0, 1), (1, 2)]);
+    graph.add_node(3);
+    let mut rng = ChaChaRng::from_seed([0; 32]);
+    assert!(uniform_spanning_tree(&graph, &mut rng).is_err());
+
+    let empty = UnGraphMap::<u32, ()>::new();
+    assert_eq!(
+        uniform_spanning_tree(&empty, &mut rng)
+            .unwrap()
+            .node_count(),
+        0
+    );
+}
+
+#[test]
+fn uniform_spanning_tree_directed_two_cycle() {
+    // 0 <-> 1 counts as a single undirected edge, so the three spanning trees
+    // of the triangle are equally likely.
+    let graph = DiGraphMap::<_, ()>::from_edges(&[(0, 1), (1, 0), (1, 2), (2, 0)]);
+    let mut rng = ChaChaRng::from_seed([3; 32]);
+    let mut counts = std::collections::HashMap::new();
+    for _ in 0..3000 {
+        let tree = uniform_spanning_tree(&graph, &mut rng).unwrap();
+        let mut edges: Vec<_> = tree.all_edges().map(|(a, b, _)| (a, b)).collect();
+        edges.sort();
+        *counts.entry(edges).or_insert(0) += 1;
+    }
+    assert_eq!(counts.len(), 3);
+    for &count in counts.values() {
+        assert!(count > 850 && count < 1150, "{:?}", counts);
+    }
+}
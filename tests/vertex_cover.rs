@@ -0,0 +1,69 @@
+#![cfg(feature = "graphmap")]
+
+use petgraph::algo::approx_vertex_cover;
+use petgraph::prelude::*;
+
+fn assert_cover(graph: &UnGraphMap<u32, ()>, cover: &[u32]) {
+    for (a, b, _) in graph.all_edges() {
+        assert!(
+            cover.contains(&a) || cover.contains(&b),
+            "edge ({}, {}) is not covered",
+            a,
+            b
+        );
+    }
+}
+
+/// Size of a minimum vertex cover, by brute force over all subsets.
+fn minimum_cover_size(graph: &UnGraphMap<u32, ()>) -> usize {
+    let nodes: Vec<u32> = graph.nodes().collect();
+    (0u32..1 << nodes.len())
+        .filter(|mask| {
+            graph.all_edges().all(|(a, b, _)| {
+                let ai = nodes.iter().position(|&n| n == a).unwrap();
+                let bi = nodes.iter().position(|&n| n == b).unwrap();
+                mask & (1 << ai) != 0 || mask & (1 << bi) != 0
+            })
+        })
+        .map(|mask| mask.count_ones() as usize)
+        .min()
+        .unwrap()
+}
+
+#[test]
+fn approx_vertex_cover_bound() {
+    let graphs = vec![
+        UnGraphMap::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 4), (4, 5)]),
+        UnGraphMap::from_edges(&[(0, 1), (0, 2), (0, 3), (0, 4)]),
+        UnGraphMap::from_edges(&[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2)]),
+        UnGraphMap::from_edges(&[
+            (0, 1),
+            (0, 2),
+            (0, 3),
+            (1, 2),
+            (1, 3),
+            (2, 3),
+            (3, 4),
+            (4, 5),
+            (5, 6),
+            (6, 7),
+        ]),
+    ];
+    for graph in &graphs {
+        let cover = approx_vertex_cover(graph);
+        assert_cover(graph, &cover);
+        assert!(cover.len() <= 2 * minimum_cover_size(graph));
+    }
+}
+
+#[test]
+fn approx_vertex_cover_misc() {
+    let mut graph = UnGraphMap::from_edges(&[(0, 1), (2, 2)]);
+    graph.add_node(9);
+    let mut cover = approx_vertex_cover(&graph);
+    assert_cover(&graph, &cover);
+    cover.sort();
+    assert_eq!(cover, vec![0, 1, 2]);
+
+    assert!(approx_vertex_cover(&UnGraphMap::<u32, ()>::new()).is_empty());
+}